@@ -1,4 +1,5 @@
 // Input from wget https://www.iana.org/assignments/language-subtag-registry/language-subtag-registry
+// Designations are the CLDR names built into Node's ICU, for the locales below.
 const readline = require('readline');
 const fs = require('fs');
 const path = require('path');
@@ -36,6 +37,9 @@ function parseConcepts() {
       } else if (line.startsWith('Subtag:')) {
         const [, subtag] = line.split(':').map((part) => part.trim());
         currentConcept.code  += subtag;
+      } else if (line.startsWith('Tag:')) {
+        const [, tag] = line.split(':').map((part) => part.trim());
+        currentConcept.code  += tag;
       } else if (line.startsWith('Description:')) {
         const [, description] = line.split(':').map((part) => part.trim());
         currentConcept.display = description;
@@ -56,18 +60,47 @@ function parseConcepts() {
         concepts.push(currentConcept);
       }
       resolve({
-        concept: concepts.filter(c => !["redundant-", "grandfathered-"].includes(c.code)),
+        concept: concepts,
         property: Array.from(properties).map(p => JSON.parse(p)),
       });
     });
   });
 }
 
+// The CLDR locales with modern coverage among the most used; English names
+// are embedded in the plugin instead.
+const DESIGNATION_LANGUAGES = [
+  'ar', 'bn', 'cs', 'da', 'de', 'el', 'es', 'fa', 'fi', 'fr', 'he', 'hi', 'hu', 'id', 'it',
+  'ja', 'ko', 'nb', 'nl', 'pl', 'pt', 'ro', 'ru', 'sv', 'th', 'tr', 'uk', 'vi', 'zh', 'zh-Hant',
+];
+const DISPLAY_NAME_TYPES = { language: 'language', extlang: 'language', script: 'script', region: 'region' };
+
+// CLDR names of a subtag; scripts get the form used inside a locale name
+// ("Simplified" rather than "Simplified Han").
+function designations(code) {
+  const [kind, ...rest] = code.split('-');
+  const subtag = rest.join('-');
+  const type = DISPLAY_NAME_TYPES[kind];
+  if (!type || subtag.includes('..')) {
+    return [];
+  }
+  return DESIGNATION_LANGUAGES.flatMap((language) => {
+    let value;
+    try {
+      value = new Intl.DisplayNames(language, { type, fallback: 'none' }).of(subtag);
+    } catch (e) {
+      return [];
+    }
+    return value && value !== subtag ? [{ language, value }] : [];
+  });
+}
+
 async function main() {
   const { concept, property } = await parseConcepts();
   console.log(JSON.stringify({...bcp47, property }));
   for (const c of concept) {
-    console.log(JSON.stringify(c));
+    const designation = designations(c.code);
+    console.log(JSON.stringify(designation.length > 0 ? { ...c, designation } : c));
   }
 }
 
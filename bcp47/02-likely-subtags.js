@@ -0,0 +1,106 @@
+// Writes src/likely_subtags.rs from the CLDR likely subtags built into Node's ICU.
+// Input from the generated CodeSystem-bcp47.ndjson.gz, for the subtags to try:
+//   node 02-likely-subtags.js > src/likely_subtags.rs
+// Only the entries `maximize` in src/likely.rs needs to give ICU's answer for
+// every language, und-script, und-region and und-script-region, and for each
+// language with every script and region, are kept.
+const zlib = require('zlib');
+const fs = require('fs');
+const path = require('path');
+
+const concepts = zlib
+  .gunzipSync(fs.readFileSync(path.join(__dirname, 'CodeSystem-bcp47.ndjson.gz')))
+  .toString()
+  .split('\n')
+  .slice(1)
+  .filter(Boolean)
+  .map(JSON.parse);
+
+function subtags(type) {
+  return concepts
+    .map((concept) => concept.code)
+    .filter((code) => code.startsWith(`${type}-`) && !code.includes('..'))
+    .map((code) => code.slice(type.length + 1));
+}
+
+function split(tag) {
+  const [language, ...rest] = tag.split('-');
+  const parts = { language };
+  for (const subtag of rest) {
+    if (/^[A-Z][a-z]{3}$/.test(subtag)) {
+      parts.script = subtag;
+    } else {
+      parts.region = subtag;
+    }
+  }
+  return parts;
+}
+
+function icuMaximize(tag) {
+  try {
+    return new Intl.Locale(tag).maximize().toString();
+  } catch (e) {
+    return null;
+  }
+}
+
+// The same lookup as `maximize` in src/likely.rs.
+function maximize(table, tag) {
+  const { language, script, region } = split(tag);
+  const keys = [];
+  if (script && region) keys.push(`${language}-${script}-${region}`);
+  if (script) keys.push(`${language}-${script}`);
+  if (region) keys.push(`${language}-${region}`);
+  keys.push(language);
+  if (script) keys.push(`und-${script}`);
+  for (const key of keys) {
+    const value = table.get(key);
+    if (value) {
+      const likely = split(value);
+      return [language === 'und' ? likely.language : language, script || likely.script, region || likely.region].join('-');
+    }
+  }
+  return tag;
+}
+
+const table = new Map();
+function add(tag) {
+  const expected = icuMaximize(tag);
+  if (expected === null) {
+    return false;
+  }
+  // ICU also replaces aliases and deprecated subtags; that is canonicalization, not likely subtags.
+  const from = split(tag);
+  const to = split(expected);
+  if ((from.language !== 'und' && from.language !== to.language) || (from.script && from.script !== to.script) || (from.region && from.region !== to.region)) {
+    return false;
+  }
+  if (maximize(table, tag) !== expected) {
+    table.set(tag, expected);
+  }
+  return expected !== tag;
+}
+
+const languages = subtags('language');
+const scripts = subtags('script');
+const regions = subtags('region');
+
+const known = languages.filter((language) => add(language));
+add('und');
+scripts.forEach((script) => add(`und-${script}`));
+regions.forEach((region) => add(`und-${region}`));
+scripts.forEach((script) => regions.forEach((region) => add(`und-${script}-${region}`)));
+known.forEach((language) => {
+  scripts.forEach((script) => add(`${language}-${script}`));
+  regions.forEach((region) => add(`${language}-${region}`));
+});
+
+const entries = [...table].sort(([a], [b]) => (a < b ? -1 : a > b ? 1 : 0));
+console.log(`// Generated by 02-likely-subtags.js from CLDR ${process.versions.cldr} (ICU ${process.versions.icu}); do not edit.`);
+console.log('');
+console.log('/// CLDR likely subtags, from the `language`, `language-script`,');
+console.log('/// `language-region`, `und-*` keys to the full `language-script-region`,');
+console.log('/// sorted by key.');
+console.log('pub static LIKELY_SUBTAGS: &[(&str, &str)] = &[');
+entries.forEach(([key, value]) => console.log(`    ("${key}", "${value}"),`));
+console.log('];');
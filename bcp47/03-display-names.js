@@ -0,0 +1,59 @@
+// Writes src/names.rs from the CLDR English display names built into Node's ICU.
+// Input from the generated CodeSystem-bcp47.ndjson.gz, for the subtags to name:
+//   node 03-display-names.js > src/names.rs
+// Subtags CLDR only names through an alias (`iw`, `BU`) are left out, so they
+// keep their registry description.
+const zlib = require('zlib');
+const fs = require('fs');
+const path = require('path');
+
+const concepts = zlib
+  .gunzipSync(fs.readFileSync(path.join(__dirname, 'CodeSystem-bcp47.ndjson.gz')))
+  .toString()
+  .split('\n')
+  .slice(1)
+  .filter(Boolean)
+  .map(JSON.parse);
+
+function subtags(type) {
+  return concepts
+    .map((concept) => concept.code)
+    .filter((code) => code.startsWith(`${type}-`) && !code.includes('..'))
+    .map((code) => code.slice(type.length + 1));
+}
+
+// The tag ICU names for a subtag, to tell aliases apart.
+const TAGS = {
+  language: (subtag) => subtag,
+  script: (subtag) => `und-${subtag}`,
+  region: (subtag) => `und-${subtag}`,
+};
+
+const entries = [];
+for (const type of Object.keys(TAGS)) {
+  const names = new Intl.DisplayNames('en', { type, fallback: 'none' });
+  for (const subtag of subtags(type)) {
+    let canonical;
+    let name;
+    try {
+      canonical = Intl.getCanonicalLocales(TAGS[type](subtag))[0];
+      name = names.of(subtag);
+    } catch (e) {
+      continue;
+    }
+    if (name && name !== subtag && canonical === TAGS[type](subtag)) {
+      entries.push([type, subtag, name]);
+    }
+  }
+}
+
+const key = ([type, subtag]) => `${type}\u0000${subtag}`;
+entries.sort((a, b) => (key(a) < key(b) ? -1 : key(a) > key(b) ? 1 : 0));
+console.log(`// Generated by 03-display-names.js from CLDR ${process.versions.cldr} (ICU ${process.versions.icu}); do not edit.`);
+console.log('');
+console.log('/// CLDR English names of language, script and region subtags, by subtag');
+console.log('/// kind and subtag, sorted. Scripts have the form used inside a locale');
+console.log('/// name, "Simplified" rather than "Simplified Han".');
+console.log('pub static NAMES: &[(&str, &str, &str)] = &[');
+entries.forEach(([type, subtag, name]) => console.log(`    ("${type}", "${subtag}", ${JSON.stringify(name)}),`));
+console.log('];');
@@ -0,0 +1,45 @@
+// Lookup caching for batch parsing.
+use crate::extism::*;
+use std::cell::RefCell;
+use std::collections::BTreeMap;
+
+/// A `TerminologyDb` that answers each distinct lookup once, so a batch of
+/// tags sharing subtags (`en-US`, `en-GB`, `es-US`) crosses to the host once
+/// per subtag record rather than once per tag.
+pub struct CachingDb<'a, T>
+where
+    T: TerminologyDb,
+{
+    db: &'a T,
+    lookups: RefCell<BTreeMap<LookupRequest, LookupResponse>>,
+}
+
+impl<'a, T> CachingDb<'a, T>
+where
+    T: TerminologyDb,
+{
+    pub fn new(db: &'a T) -> Self {
+        CachingDb {
+            db,
+            lookups: RefCell::new(BTreeMap::new()),
+        }
+    }
+}
+
+impl<T> TerminologyDb for CachingDb<'_, T>
+where
+    T: TerminologyDb,
+{
+    fn db_lookup(&self, req: LookupRequest) -> LookupResponse {
+        if let Some(response) = self.lookups.borrow().get(&req) {
+            return response.clone();
+        }
+        let response = self.db.db_lookup(req.clone());
+        self.lookups.borrow_mut().insert(req, response.clone());
+        response
+    }
+
+    fn db_subsumes(&self, req: SubsumesRequest) -> SubsumesResponse {
+        self.db.db_subsumes(req)
+    }
+}
@@ -0,0 +1,143 @@
+// Language collections, ISO 639-5 "Codes for the representation of names of
+// languages - Part 5: Alpha-3 code for language families and groups".
+
+/// Each collection (registry `Scope: collection`) with the collections and
+/// languages it directly includes, after the ISO 639-5 hierarchy. ISO 639-5
+/// names the collections and how they nest but publishes no list of the
+/// languages in each, so this covers only the families we see in practice:
+/// 29 of the registry's 116 collections. For languages outside it membership
+/// is unknown rather than absent, and parse says so when the collection
+/// property is requested.
+/// Narrower collections come before the ones including them, so collections
+/// are found nearest first.
+static COLLECTIONS: &[(&str, &[&str])] = &[
+    (
+        "gmw",
+        &[
+            "af", "de", "en", "fy", "gsw", "lb", "nds", "nl", "sco", "yi",
+        ],
+    ),
+    ("gmq", &["da", "fo", "is", "nb", "nn", "no", "sv"]),
+    ("gem", &["gme", "gmq", "gmw"]),
+    ("zle", &["be", "rue", "ru", "uk"]),
+    ("zls", &["bg", "bs", "cu", "hr", "mk", "sh", "sl", "sr"]),
+    ("zlw", &["cs", "csb", "dsb", "hsb", "pl", "sk", "szl"]),
+    ("sla", &["zle", "zls", "zlw"]),
+    (
+        "roa",
+        &[
+            "an", "ast", "ca", "co", "es", "fr", "gl", "it", "lad", "oc", "pt", "rm", "ro", "sc",
+            "wa",
+        ],
+    ),
+    ("itc", &["la", "roa"]),
+    ("cel", &["br", "cy", "ga", "gd", "gv", "kw"]),
+    ("bat", &["lt", "ltg", "lv", "prg", "sgs"]),
+    (
+        "inc",
+        &[
+            "as", "bn", "gu", "hi", "mr", "ne", "or", "pa", "sa", "sd", "si", "ur",
+        ],
+    ),
+    ("ira", &["bal", "fa", "ku", "os", "ps", "tg"]),
+    ("iir", &["inc", "ira"]),
+    (
+        "ine",
+        &["bat", "cel", "gem", "grk", "hy", "iir", "itc", "sla", "sq"],
+    ),
+    ("grk", &["el", "grc"]),
+    ("sem", &["am", "ar", "he", "mt", "ti"]),
+    ("afa", &["ber", "cus", "sem"]),
+    (
+        "trk",
+        &["az", "ba", "cv", "kk", "ky", "tk", "tr", "tt", "ug", "uz"],
+    ),
+    ("smi", &["se", "sma", "smj", "smn", "sms"]),
+    ("fiu", &["et", "fi", "hu", "smi"]),
+    ("urj", &["fiu"]),
+    (
+        "zhx",
+        &["cmn", "gan", "hak", "hsn", "nan", "wuu", "yue", "zh"],
+    ),
+    ("jpx", &["ja", "ryu"]),
+    ("tbq", &["bo", "my"]),
+    ("sit", &["tbq", "zhx"]),
+    (
+        "bnt",
+        &["kg", "lg", "ln", "rn", "rw", "sn", "sw", "xh", "zu"],
+    ),
+    (
+        "poz",
+        &[
+            "fj", "haw", "id", "jv", "mg", "mi", "ms", "sm", "su", "tl", "to",
+        ],
+    ),
+    ("map", &["poz"]),
+];
+
+/// The collections that include a language or collection, nearest first, so
+/// `pl` gives `zlw`, `sla` and `ine`.
+pub fn collections(language: &str) -> Vec<&'static str> {
+    let mut collections: Vec<&'static str> = Vec::new();
+    let mut queue = vec![language];
+    while !queue.is_empty() {
+        let mut next = Vec::new();
+        for member in queue {
+            for (collection, _) in COLLECTIONS
+                .iter()
+                .filter(|(_, members)| members.contains(&member))
+            {
+                if !collections.contains(collection) {
+                    collections.push(collection);
+                    next.push(*collection);
+                }
+            }
+        }
+        queue = next;
+    }
+    collections
+}
+
+/// Whether the table knows which languages `collection` includes.
+pub fn covers(collection: &str) -> bool {
+    COLLECTIONS.iter().any(|(c, _)| *c == collection)
+}
+
+/// Whether `collection` includes `language`, directly or through narrower
+/// collections.
+pub fn includes(collection: &str, language: &str) -> bool {
+    collections(language).contains(&collection)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collections() {
+        assert_eq!(collections("pl"), vec!["zlw", "sla", "ine"]);
+        assert_eq!(collections("en"), vec!["gmw", "gem", "ine"]);
+        assert_eq!(collections("se"), vec!["smi", "fiu", "urj"]);
+        assert_eq!(collections("sla"), vec!["ine"]);
+        assert!(collections("ine").is_empty());
+        assert!(collections("tlh").is_empty());
+    }
+
+    #[test]
+    fn test_includes() {
+        assert!(includes("sla", "pl"));
+        assert!(includes("sla", "zlw"));
+        assert!(includes("ine", "hi"));
+        assert!(includes("zhx", "yue"));
+        assert!(!includes("sla", "de"));
+        assert!(!includes("sla", "sla"));
+    }
+
+    #[test]
+    fn test_covers() {
+        assert!(covers("sla"));
+        assert!(covers("ine"));
+        assert!(covers("jpx"));
+        assert!(!covers("pl"));
+    }
+}
@@ -0,0 +1,308 @@
+// Concept displays: localized subtag names put together by the CLDR locale
+// display name algorithm, UTS #35 Part 2 §1.1.
+use crate::extism::*;
+use crate::names::NAMES;
+use crate::unicode::{key_definition, UnicodeExtension};
+
+/// The display language and style a parse request asks for.
+#[derive(Clone, Copy, Default)]
+pub struct DisplayOptions<'a> {
+    pub language: Option<&'a str>,
+    pub style: Option<DisplayStyle>,
+}
+
+impl<'a> From<&'a ParseRequest> for DisplayOptions<'a> {
+    fn from(request: &'a ParseRequest) -> Self {
+        DisplayOptions {
+            language: request.display_language.as_deref(),
+            style: request.display_style,
+        }
+    }
+}
+
+impl DisplayOptions<'_> {
+    /// Whether names come out in English.
+    pub fn is_english(&self) -> bool {
+        self.language
+            .is_none_or(|language| primary_language(language) == "en")
+    }
+}
+
+/// English `alt="short"` and `alt="menu"` names, by style, subtag kind and
+/// subtag.
+static ALT_NAMES: &[(DisplayStyle, &str, &str, &str)] = &[
+    (DisplayStyle::Short, "region", "GB", "UK"),
+    (DisplayStyle::Short, "region", "HK", "Hong Kong"),
+    (DisplayStyle::Short, "region", "MO", "Macao"),
+    (DisplayStyle::Short, "region", "PS", "Palestine"),
+    (DisplayStyle::Short, "region", "US", "US"),
+    (DisplayStyle::Menu, "language", "cmn", "Chinese, Mandarin"),
+    (DisplayStyle::Menu, "language", "hak", "Chinese, Hakka"),
+    (DisplayStyle::Menu, "language", "nan", "Chinese, Min Nan"),
+    (DisplayStyle::Menu, "language", "yue", "Chinese, Cantonese"),
+];
+
+/// English names of `-u-` keyword types; a type missing here is shown with
+/// its key, as in "Currency: eur".
+static TYPE_NAMES: &[(&str, &str, &str)] = &[
+    ("ca", "buddhist", "Buddhist Calendar"),
+    ("ca", "gregory", "Gregorian Calendar"),
+    ("ca", "hebrew", "Hebrew Calendar"),
+    ("ca", "islamic", "Hijri Calendar"),
+    ("ca", "japanese", "Japanese Calendar"),
+    ("co", "phonebk", "Phonebook Sort Order"),
+    ("co", "pinyin", "Pinyin Sort Order"),
+    ("co", "stroke", "Stroke Order"),
+    ("co", "trad", "Traditional Sort Order"),
+    ("hc", "h11", "12 Hour System (0–11)"),
+    ("hc", "h12", "12 Hour System (1–12)"),
+    ("hc", "h23", "24 Hour System (0–23)"),
+    ("ms", "metric", "Metric System"),
+    ("ms", "uksystem", "Imperial Measurement System"),
+    ("ms", "ussystem", "US Measurement System"),
+    ("nu", "arab", "Arabic-Indic Digits"),
+    ("nu", "hanidec", "Chinese Decimal Numerals"),
+    ("nu", "latn", "Western Digits"),
+];
+
+/// `localeDisplayPattern` of a display language: the pattern, the
+/// separator between qualifiers and the key-type pattern.
+fn patterns(language: Option<&str>) -> (&'static str, &'static str, &'static str) {
+    match language.map(primary_language).as_deref() {
+        Some("ja" | "zh") => ("{0}（{1}）", "、", "{0}：{1}"),
+        _ => ("{0} ({1})", ", ", "{0}: {1}"),
+    }
+}
+
+/// The primary language subtag of a display language, lowercased.
+fn primary_language(language: &str) -> String {
+    language
+        .split(['-', '_'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase()
+}
+
+/// The name of a subtag in `language`: the record's designation in that
+/// language, or failing that in its primary language. `None` means the
+/// English name applies.
+pub fn localized_name(record: &Concept, language: &str) -> Option<String> {
+    let primary = primary_language(language);
+    let designation = |matches: &dyn Fn(&str) -> bool| {
+        record
+            .designations
+            .iter()
+            .find(|d| d.language.as_deref().is_some_and(matches))
+            .map(|d| d.value.clone())
+    };
+    designation(&|l| l.eq_ignore_ascii_case(language))
+        .or_else(|| designation(&|l| primary_language(l) == primary))
+}
+
+/// The CLDR English name of a subtag.
+fn english_name(kind: &str, subtag: &str) -> Option<&'static str> {
+    NAMES
+        .binary_search_by(|(k, s, _)| (*k, *s).cmp(&(kind, subtag)))
+        .ok()
+        .map(|index| NAMES[index].2)
+}
+
+/// The name of a subtag for a concept display: an `alt` name for the style,
+/// then the localized name, then the CLDR English name, then the record's
+/// registry description, and failing all of those the subtag itself.
+pub fn subtag_name(
+    record: Option<&Concept>,
+    kind: &str,
+    subtag: &str,
+    options: DisplayOptions,
+) -> String {
+    // An extlang is named as the language it also is.
+    let kind = match kind {
+        "extlang" => "language",
+        kind => kind,
+    };
+    options
+        .style
+        .filter(|_| options.is_english())
+        .and_then(|style| {
+            ALT_NAMES
+                .iter()
+                .find(|(s, k, c, _)| *s == style && *k == kind && *c == subtag)
+                .map(|(_, _, _, name)| name.to_string())
+        })
+        .or_else(|| {
+            let language = options.language?;
+            localized_name(record?, language)
+        })
+        .or_else(|| english_name(kind, subtag).map(str::to_string))
+        .or_else(|| record.and_then(|record| record.display.clone()))
+        .unwrap_or_else(|| subtag.to_string())
+}
+
+/// One qualifier per keyword of a `-u-` extension: the type's English name
+/// where we have one, otherwise the key and type through the key-type
+/// pattern. The rest of the display stays in the display language.
+pub fn keyword_names(extension: &UnicodeExtension, options: DisplayOptions) -> Vec<String> {
+    let (_, _, key_type_pattern) = patterns(options.language);
+    extension
+        .keywords
+        .iter()
+        .map(|keyword| {
+            let value = keyword.value();
+            TYPE_NAMES
+                .iter()
+                .find(|(k, t, _)| *k == keyword.key && *t == value)
+                .map(|(_, _, name)| name.to_string())
+                .unwrap_or_else(|| {
+                    let key = key_definition(&keyword.key)
+                        .map_or(keyword.key.as_str(), |definition| definition.description);
+                    key_type_pattern.replace("{0}", key).replace("{1}", &value)
+                })
+        })
+        .collect()
+}
+
+/// The private use subtags as a qualifier.
+pub fn private_use_name(private_use: &[String], options: DisplayOptions) -> String {
+    let (_, _, key_type_pattern) = patterns(options.language);
+    key_type_pattern
+        .replace("{0}", "Private-Use")
+        .replace("{1}", &private_use.join("-"))
+}
+
+/// Puts a language name and its qualifiers (script, region, variants, then
+/// keywords) into the locale display pattern. Parentheses in the names
+/// become brackets so they don't read as part of the pattern.
+pub fn locale_display_name(
+    language: &str,
+    qualifiers: &[String],
+    options: DisplayOptions,
+) -> String {
+    if qualifiers.is_empty() {
+        return language.to_string();
+    }
+    let (pattern, separator, _) = patterns(options.language);
+    let bracketed = |name: &str| name.replace('(', "[").replace(')', "]");
+    let qualifiers = qualifiers
+        .iter()
+        .map(|q| bracketed(q))
+        .collect::<Vec<_>>()
+        .join(separator);
+    pattern
+        .replace("{0}", &bracketed(language))
+        .replace("{1}", &qualifiers)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn record(designations: Vec<(&str, &str)>) -> Concept {
+        Concept {
+            code: "region-CH".to_string(),
+            display: Some("Switzerland".to_string()),
+            properties: Vec::new(),
+            designations: designations
+                .into_iter()
+                .map(|(language, value)| Designation {
+                    language: Some(language.to_string()),
+                    value: value.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_localized_name() {
+        let swiss = record(vec![("de", "die Schweiz"), ("de-CH", "Schwiiz")]);
+        assert_eq!(localized_name(&swiss, "de-CH").unwrap(), "Schwiiz");
+        assert_eq!(localized_name(&swiss, "de-AT").unwrap(), "die Schweiz");
+        assert_eq!(localized_name(&swiss, "fr"), None);
+    }
+
+    #[test]
+    fn test_locale_display_name() {
+        let options = DisplayOptions::default();
+        let qualifiers = vec!["Traditional".to_string(), "Taiwan".to_string()];
+        assert_eq!(
+            locale_display_name("Chinese", &qualifiers, options),
+            "Chinese (Traditional, Taiwan)"
+        );
+        assert_eq!(
+            locale_display_name("Burmese", &["Myanmar (Burma)".to_string()], options),
+            "Burmese (Myanmar [Burma])"
+        );
+        assert_eq!(locale_display_name("English", &[], options), "English");
+        let japanese = DisplayOptions {
+            language: Some("ja"),
+            ..options
+        };
+        assert_eq!(
+            locale_display_name("英語", &["アメリカ合衆国".to_string()], japanese),
+            "英語（アメリカ合衆国）"
+        );
+    }
+
+    #[test]
+    fn test_subtag_name_sources() {
+        assert!(NAMES
+            .windows(2)
+            .all(|w| (w[0].0, w[0].1) < (w[1].0, w[1].1)));
+        let registry = |code: &str, display: &str| Concept {
+            code: code.to_string(),
+            display: Some(display.to_string()),
+            ..Concept::default()
+        };
+        let options = DisplayOptions::default();
+        let hant = registry("script-Hant", "Han (Traditional variant)");
+        assert_eq!(
+            subtag_name(Some(&hant), "script", "Hant", options),
+            "Traditional"
+        );
+        let yue = registry("extlang-yue", "Yue Chinese");
+        assert_eq!(
+            subtag_name(Some(&yue), "extlang", "yue", options),
+            "Cantonese"
+        );
+        // CLDR has no name for Ghotuo, nor for `iw` but through its alias.
+        let ghotuo = registry("language-aaa", "Ghotuo");
+        assert_eq!(
+            subtag_name(Some(&ghotuo), "language", "aaa", options),
+            "Ghotuo"
+        );
+        let iw = registry("language-iw", "Hebrew");
+        assert_eq!(subtag_name(Some(&iw), "language", "iw", options), "Hebrew");
+        assert_eq!(english_name("language", "iw"), None);
+    }
+
+    #[test]
+    fn test_subtag_name_styles() {
+        let us = Concept {
+            code: "region-US".to_string(),
+            display: Some("United States".to_string()),
+            designations: vec![Designation {
+                language: Some("de".to_string()),
+                value: "Vereinigte Staaten".to_string(),
+            }],
+            ..Concept::default()
+        };
+        let short = DisplayOptions {
+            style: Some(DisplayStyle::Short),
+            ..DisplayOptions::default()
+        };
+        assert_eq!(subtag_name(Some(&us), "region", "US", short), "US");
+        assert_eq!(
+            subtag_name(Some(&us), "region", "US", DisplayOptions::default()),
+            "United States"
+        );
+        let german = DisplayOptions {
+            language: Some("de"),
+            ..short
+        };
+        assert_eq!(
+            subtag_name(Some(&us), "region", "US", german),
+            "Vereinigte Staaten"
+        );
+        assert_eq!(subtag_name(None, "region", "QM", short), "QM");
+    }
+}
@@ -34,6 +34,7 @@ impl ParseError<&str> for ParseDetail {
                 "Error parsing input: {:?} with kind: {:?}",
                 input, kind
             )),
+            location: Location::default(),
         }
     }
 
@@ -45,6 +46,7 @@ impl ParseError<&str> for ParseDetail {
                 "Error parsing input: {:?} with kind: {:?}",
                 input, kind
             )),
+            location: Location::default(),
         }
     }
 }
@@ -52,14 +54,19 @@ impl ParseError<&str> for ParseDetail {
 pub trait TerminologyEngine<T: TerminologyDb> {
     fn metadata(&self) -> String;
     fn parse(&self, req: ParseRequest) -> ParseResponse;
+    fn parse_batch(&self, req: ParseBatchRequest) -> ParseBatchResponse;
     fn subsumes(&self, req: SubsumesRequest) -> SubsumesResponse;
+    fn filter(&self, req: FilterRequest) -> FilterResponse;
+    fn lookup(&self, req: RangeLookupRequest) -> RangeLookupResponse;
 }
 
 pub trait TerminologyDb {
     fn db_lookup(&self, req: LookupRequest) -> LookupResponse;
+    #[allow(dead_code)]
     fn db_subsumes(&self, req: SubsumesRequest) -> SubsumesResponse;
 }
 
+#[allow(dead_code)]
 pub trait WithDb {
     fn db(&self) -> &dyn TerminologyDb;
 }
@@ -101,6 +108,7 @@ impl TerminologyDb for HostReal {
     Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Clone, Debug, ToBytes, FromBytes,
 )]
 #[encoding(Json)]
+#[allow(clippy::enum_variant_names)]
 pub enum ValueX {
     #[serde(rename = "valueString")]
     ValueString(String),
@@ -141,8 +149,19 @@ pub struct Concept {
     pub code: String,
     pub display: Option<String>,
     pub properties: Vec<Property>,
+    /// Displays in other languages, from the host's `ConceptDesignations`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub designations: Vec<Designation>,
 }
 
+#[derive(
+    Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Clone, Debug, ToBytes, FromBytes,
+)]
+#[encoding(Json)]
+pub struct Designation {
+    pub language: Option<String>,
+    pub value: String,
+}
 
 #[derive(
     Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Clone, Debug, ToBytes, FromBytes,
@@ -165,6 +184,39 @@ pub struct ParseDetail {
     pub key: String,
     #[serde(flatten)]
     pub value: ValueX,
+    #[serde(flatten)]
+    pub location: Location,
+}
+
+/// Where in the parsed code a detail applies. Offsets are in characters,
+/// `end` exclusive; `subtagIndex` counts the `-`-separated subtags from 0.
+/// Details about the tag as a whole have no location.
+#[derive(
+    Default, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Clone, Debug, ToBytes, FromBytes,
+)]
+#[encoding(Json)]
+pub struct Location {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub start: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub end: Option<usize>,
+    #[serde(
+        rename = "subtagIndex",
+        default,
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub subtag_index: Option<usize>,
+}
+
+impl Location {
+    /// The subtag at `index`; its offsets are filled in once the whole code
+    /// is known.
+    pub fn subtag(index: usize) -> Self {
+        Location {
+            subtag_index: Some(index),
+            ..Location::default()
+        }
+    }
 }
 
 #[derive(
@@ -176,6 +228,24 @@ pub struct ParseResponse {
     pub concept: Option<Concept>,
 }
 
+/// Several tags parsed in one call, sharing registry lookups.
+#[derive(
+    Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Clone, Debug, ToBytes, FromBytes,
+)]
+#[encoding(Json)]
+pub struct ParseBatchRequest {
+    pub requests: Vec<ParseRequest>,
+}
+
+#[derive(
+    Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Clone, Debug, ToBytes, FromBytes,
+)]
+#[encoding(Json)]
+pub struct ParseBatchResponse {
+    /// One response per request, in request order.
+    pub responses: Vec<ParseResponse>,
+}
+
 // terminology_db.rs
 
 #[derive(
@@ -184,7 +254,13 @@ pub struct ParseResponse {
 #[encoding(Json)]
 pub struct LookupRequest {
     pub code: String,
+    /// Registry fields the engine reads from the record; the host may leave
+    /// out other properties. All of them when absent.
     pub properties: Option<Vec<String>>,
+    /// Whether the engine reads the record's designations, which it only
+    /// does for a display language; the host may leave them out otherwise.
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub designations: bool,
 }
 
 #[derive(
@@ -202,6 +278,30 @@ pub struct LookupResponse {
 pub struct SubsumesRequest {
     pub ancestor: String,
     pub descendant: String,
+    /// Relations to follow beyond comparing the tags' own subtags.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub modes: Vec<SubsumesMode>,
+}
+
+#[derive(
+    Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug, ToBytes, FromBytes,
+)]
+#[encoding(Json)]
+#[serde(rename_all = "kebab-case")]
+pub enum SubsumesMode {
+    /// A macrolanguage subsumes the languages it encompasses, so `zh`
+    /// subsumes `cmn-CN`.
+    Macrolanguage,
+    /// The descendant is compared with its likely subtags added, so
+    /// `zh-Hant-TW` subsumes `zh-TW` and `en-US` subsumes `en`, and then so is
+    /// the ancestor, so `und-Hant` subsumes `zh-TW`. A side CLDR has no likely
+    /// subtags for is compared as it is.
+    LikelySubtags,
+    /// An ISO 639-5 collection subsumes the languages and collections it
+    /// includes, so `sla` subsumes `pl-PL`. Membership is only known for the
+    /// common families; outside them it is unknown, and this does not
+    /// subsume.
+    Collection,
 }
 
 #[derive(
@@ -215,12 +315,115 @@ pub struct SubsumesResponse {
 // terminology_engine.rs
 
 #[derive(
-    Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Clone, Debug, ToBytes, FromBytes,
+    Default, Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Clone, Debug, ToBytes, FromBytes,
 )]
 #[encoding(Json)]
 pub struct ParseRequest {
     pub code: String,
+    /// Property codes to return on the concept; all of them when absent.
     pub properties: Option<Vec<String>>,
+    /// Without a level, registry problems are reported as warnings and the
+    /// tag is not required to be valid.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub conformance: Option<Conformance>,
+    /// Language of the concept display, e.g. `de`; English when absent or
+    /// when no name is known in that language.
+    #[serde(
+        default,
+        rename = "displayLanguage",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub display_language: Option<String>,
+    #[serde(
+        default,
+        rename = "displayStyle",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub display_style: Option<DisplayStyle>,
+    /// Date (`YYYY-MM-DD`) to validate against the registry as it stood
+    /// then: later subtags and tags are not yet valid, later deprecations not
+    /// yet in force. A dateTime counts as its date; anything else is rejected
+    /// with an Error detail.
+    #[serde(default, rename = "asOf", skip_serializing_if = "Option::is_none")]
+    pub as_of: Option<String>,
+}
+
+/// Which CLDR names a concept display uses; long when absent.
+#[derive(
+    Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug, ToBytes, FromBytes,
+)]
+#[encoding(Json)]
+#[serde(rename_all = "kebab-case")]
+pub enum DisplayStyle {
+    /// The standard names: "English (United States)".
+    Long,
+    /// `alt="short"` names where CLDR has them: "English (US)".
+    Short,
+    /// `alt="menu"` names, which put the family first for sorting in
+    /// lists: "Chinese, Cantonese (Hong Kong)".
+    Menu,
+}
+
+/// How strictly `parse` checks a tag; the levels of RFC 5646 §2.2.9.
+#[derive(
+    Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Debug, ToBytes, FromBytes,
+)]
+#[encoding(Json)]
+#[serde(rename_all = "kebab-case")]
+pub enum Conformance {
+    /// Syntax only; the registry is not consulted.
+    WellFormed,
+    /// Every subtag is registered and the variant and extlang prefix rules
+    /// hold.
+    Valid,
+    /// Valid and already in canonical form.
+    Canonical,
+}
+
+/// RFC 4647 filtering: the tags matching any of the language ranges.
+#[derive(
+    Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Clone, Debug, ToBytes, FromBytes,
+)]
+#[encoding(Json)]
+pub struct FilterRequest {
+    pub ranges: Vec<String>,
+    pub tags: Vec<String>,
+    /// Extended filtering, where `*` stands for any subtags, rather than
+    /// basic filtering.
+    #[serde(default)]
+    pub extended: bool,
+}
+
+#[derive(
+    Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Clone, Debug, ToBytes, FromBytes,
+)]
+#[encoding(Json)]
+pub struct FilterResponse {
+    /// Matching tags as given, in request order.
+    pub tags: Vec<String>,
+    pub details: Vec<ParseDetail>,
+}
+
+/// RFC 4647 lookup: the single best tag for a priority list of ranges.
+#[derive(
+    Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Clone, Debug, ToBytes, FromBytes,
+)]
+#[encoding(Json)]
+pub struct RangeLookupRequest {
+    pub ranges: Vec<String>,
+    pub tags: Vec<String>,
+    /// Returned when no range matches.
+    #[serde(default)]
+    pub default: Option<String>,
+}
+
+#[derive(
+    Deserialize, Serialize, PartialEq, Eq, PartialOrd, Ord, Clone, Debug, ToBytes, FromBytes,
+)]
+#[encoding(Json)]
+pub struct RangeLookupResponse {
+    pub tag: Option<String>,
+    pub details: Vec<ParseDetail>,
 }
 
 pub use lazy_static::lazy_static;
@@ -242,10 +445,25 @@ macro_rules! define_terminology_engine {
             Ok(TERMINOLOGY_ENGINE.parse(req))
         }
 
+        #[plugin_fn]
+        pub fn parse_batch(req: ParseBatchRequest) -> FnResult<ParseBatchResponse> {
+            Ok(TERMINOLOGY_ENGINE.parse_batch(req))
+        }
+
         #[plugin_fn]
         pub fn subsumes(req: SubsumesRequest) -> FnResult<SubsumesResponse> {
             Ok(TERMINOLOGY_ENGINE.subsumes(req))
         }
+
+        #[plugin_fn]
+        pub fn filter(req: FilterRequest) -> FnResult<FilterResponse> {
+            Ok(TERMINOLOGY_ENGINE.filter(req))
+        }
+
+        #[plugin_fn]
+        pub fn lookup(req: RangeLookupRequest) -> FnResult<RangeLookupResponse> {
+            Ok(TERMINOLOGY_ENGINE.lookup(req))
+        }
     };
 }
 
@@ -261,6 +479,10 @@ mod tests {
         let request = ParseRequest {
             code: "test_code".to_string(),
             properties: Some(vec!["property1".to_string(), "property2".to_string()]),
+            conformance: Some(Conformance::Valid),
+            display_language: Some("de".to_string()),
+            display_style: Some(DisplayStyle::Short),
+            as_of: None,
             // Add more properties here as needed
         };
 
@@ -270,6 +492,7 @@ mod tests {
                     severity: Severity::Error,
                     key: "key1".to_string(),
                     value: ValueX::ValueCode("OK".to_string()),
+                    location: Location::default(),
                 },
                 // Add more Detail structs here as needed
             ],
@@ -301,4 +524,58 @@ mod tests {
         println!("Request: {}", request_json);
         println!("Response: {}", response_json);
     }
+
+    #[test]
+    fn test_parse_detail_location() {
+        let detail = ParseDetail {
+            severity: Severity::Warning,
+            key: "region".to_string(),
+            value: ValueX::ValueString("Invalid region subtag: ZZ".to_string()),
+            location: Location {
+                start: Some(3),
+                end: Some(5),
+                subtag_index: Some(1),
+            },
+        };
+        let json = to_string(&detail).unwrap();
+        assert_eq!(
+            json,
+            r#"{"severity":"warning","key":"region","valueString":"Invalid region subtag: ZZ","start":3,"end":5,"subtagIndex":1}"#
+        );
+        assert_eq!(serde_json::from_str::<ParseDetail>(&json).unwrap(), detail);
+
+        let unlocated = ParseDetail {
+            location: Location::default(),
+            ..detail
+        };
+        let json = to_string(&unlocated).unwrap();
+        assert_eq!(
+            json,
+            r#"{"severity":"warning","key":"region","valueString":"Invalid region subtag: ZZ"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<ParseDetail>(&json).unwrap(),
+            unlocated
+        );
+    }
+
+    #[test]
+    fn test_parse_batch_request() {
+        let json = r#"{"requests":[{"code":"en-US","properties":null},{"code":"de","properties":["canonical"]}]}"#;
+        let request = serde_json::from_str::<ParseBatchRequest>(json).unwrap();
+        assert_eq!(request.requests.len(), 2);
+        assert_eq!(request.requests[1].code, "de");
+        assert_eq!(to_string(&request).unwrap(), json);
+
+        let response = ParseBatchResponse {
+            responses: vec![ParseResponse {
+                details: Vec::new(),
+                concept: None,
+            }],
+        };
+        assert_eq!(
+            to_string(&response).unwrap(),
+            r#"{"responses":[{"details":[],"concept":null}]}"#
+        );
+    }
 }
@@ -0,0 +1,410 @@
+// The RFC 5646 §2.1 `Language-Tag` ABNF, as nom parsers over the tag text.
+use crate::extism::*;
+use crate::{Extension, LanguageTag};
+use nom::bytes::complete::take_while;
+use nom::character::complete::char;
+use nom::combinator::{cut, opt, verify};
+use nom::multi::{many0, many1, many_m_n};
+use nom::sequence::preceded;
+use nom::IResult;
+
+/// A well-formed tag: either a `langtag`/`privateuse` tag, which is
+/// decomposed into subtags, or one of the `grandfathered` tags.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Tag {
+    Language(LanguageTag),
+    Grandfathered(&'static str),
+}
+
+/// Tags from the `irregular` and `regular` alternatives of the RFC 5646 §2.1
+/// `grandfathered` production, spelled as they appear in the registry.
+const GRANDFATHERED: &[&str] = &[
+    "en-GB-oed",
+    "i-ami",
+    "i-bnn",
+    "i-default",
+    "i-enochian",
+    "i-hak",
+    "i-klingon",
+    "i-lux",
+    "i-mingo",
+    "i-navajo",
+    "i-pwn",
+    "i-tao",
+    "i-tay",
+    "i-tsu",
+    "sgn-BE-FR",
+    "sgn-BE-NL",
+    "sgn-CH-DE",
+    "art-lojban",
+    "cel-gaulish",
+    "no-bok",
+    "no-nyn",
+    "zh-guoyu",
+    "zh-hakka",
+    "zh-min",
+    "zh-min-nan",
+    "zh-xiang",
+];
+
+fn grandfathered(code: &str) -> Option<&'static str> {
+    GRANDFATHERED
+        .iter()
+        .find(|tag| tag.eq_ignore_ascii_case(code))
+        .copied()
+}
+
+/// Where the grammar stopped matching and what it would have accepted there.
+#[derive(Debug, PartialEq)]
+struct GrammarError<'a> {
+    input: &'a str,
+    key: &'static str,
+    expected: &'static str,
+}
+
+impl<'a> nom::error::ParseError<&'a str> for GrammarError<'a> {
+    fn from_error_kind(input: &'a str, _kind: nom::error::ErrorKind) -> Self {
+        GrammarError {
+            input,
+            key: "language",
+            expected: "a subtag",
+        }
+    }
+
+    fn append(_input: &'a str, _kind: nom::error::ErrorKind, other: Self) -> Self {
+        other
+    }
+}
+
+type Parsed<'a, O> = IResult<&'a str, O, GrammarError<'a>>;
+
+/// Reports any failure of `parser` as expecting `expected` where it started.
+fn expect<'a, O>(
+    key: &'static str,
+    expected: &'static str,
+    mut parser: impl FnMut(&'a str) -> Parsed<'a, O>,
+) -> impl FnMut(&'a str) -> Parsed<'a, O> {
+    move |input| {
+        parser(input).map_err(|e| {
+            e.map(|_| GrammarError {
+                input,
+                key,
+                expected,
+            })
+        })
+    }
+}
+
+/// The text up to the next `-`, which every production below then checks.
+fn subtag(input: &str) -> Parsed<'_, &str> {
+    take_while(|c| c != '-')(input)
+}
+
+fn alpha(subtag: &str, lengths: std::ops::RangeInclusive<usize>) -> bool {
+    lengths.contains(&subtag.len()) && subtag.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+fn alphanum(subtag: &str, lengths: std::ops::RangeInclusive<usize>) -> bool {
+    lengths.contains(&subtag.len()) && subtag.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+const LANGUAGE: &str = "a language subtag of 2-8 letters";
+const EXTLANG: &str = "an extlang subtag of 3 letters";
+const SCRIPT: &str = "a script subtag of 4 letters";
+const REGION: &str = "a region subtag of 2 letters or 3 digits";
+const VARIANT: &str =
+    "a variant subtag of 5-8 letters or digits, or a digit followed by 3 letters or digits";
+const SINGLETON: &str = "an extension singleton";
+const EXTENSION: &str = "an extension subtag of 2-8 letters or digits";
+const PRIVATE_USE: &str = "a private use subtag of 1-8 letters or digits";
+
+/// `language = 2*3ALPHA ["-" extlang] / 4ALPHA / 5*8ALPHA`, with
+/// `extlang = 3ALPHA *2("-" 3ALPHA)`.
+fn language(input: &str) -> Parsed<'_, (String, Vec<String>)> {
+    let (input, language) =
+        expect("language", LANGUAGE, verify(subtag, |s| alpha(s, 2..=8)))(input)?;
+    if language.len() > 3 {
+        return Ok((input, (language.to_string(), Vec::new())));
+    }
+    let (input, extlang) = many_m_n(
+        0,
+        3,
+        preceded(char('-'), verify(subtag, |s| alpha(s, 3..=3))),
+    )(input)?;
+    Ok((input, (language.to_string(), to_strings(&extlang))))
+}
+
+/// `script = 4ALPHA`
+fn script(input: &str) -> Parsed<'_, &str> {
+    verify(subtag, |s| alpha(s, 4..=4))(input)
+}
+
+/// `region = 2ALPHA / 3DIGIT`
+fn region(input: &str) -> Parsed<'_, &str> {
+    verify(subtag, |s: &str| {
+        alpha(s, 2..=2) || (s.len() == 3 && s.chars().all(|c| c.is_ascii_digit()))
+    })(input)
+}
+
+/// `variant = 5*8alphanum / (DIGIT 3alphanum)`
+fn variant(input: &str) -> Parsed<'_, &str> {
+    verify(subtag, |s: &str| {
+        alphanum(s, 5..=8) || (alphanum(s, 4..=4) && s.as_bytes()[0].is_ascii_digit())
+    })(input)
+}
+
+/// `extension = singleton 1*("-" (2*8alphanum))`, where a singleton is any
+/// letter or digit other than `x`. Once a singleton is seen at least one
+/// extension subtag must follow.
+fn extension(input: &str) -> Parsed<'_, Extension> {
+    let (input, singleton) = verify(subtag, |s: &str| {
+        alphanum(s, 1..=1) && !s.eq_ignore_ascii_case("x")
+    })(input)?;
+    let (input, parts) = cut(many1(expect(
+        "extension",
+        EXTENSION,
+        preceded(char('-'), verify(subtag, |s| alphanum(s, 2..=8))),
+    )))(input)?;
+    Ok((
+        input,
+        Extension {
+            singleton: singleton.chars().next().unwrap(),
+            parts: to_strings(&parts),
+        },
+    ))
+}
+
+/// `privateuse = "x" 1*("-" (1*8alphanum))`
+fn privateuse(input: &str) -> Parsed<'_, Vec<String>> {
+    let (input, _) = verify(subtag, |s: &str| s.eq_ignore_ascii_case("x"))(input)?;
+    let (input, parts) = cut(many1(expect(
+        "privateUse",
+        PRIVATE_USE,
+        preceded(char('-'), verify(subtag, |s| alphanum(s, 1..=8))),
+    )))(input)?;
+    Ok((input, to_strings(&parts)))
+}
+
+/// `langtag = language ["-" script] ["-" region] *("-" variant)
+/// *("-" extension) ["-" privateuse]`
+fn langtag(input: &str) -> Parsed<'_, LanguageTag> {
+    let (input, (language, extlang)) = language(input)?;
+    let (input, script) = opt(preceded(char('-'), script))(input)?;
+    let (input, region) = opt(preceded(char('-'), region))(input)?;
+    let (input, variants) = many0(preceded(char('-'), variant))(input)?;
+    let (input, extensions) = many0(preceded(char('-'), extension))(input)?;
+    let (input, private_use) = opt(preceded(char('-'), privateuse))(input)?;
+    Ok((
+        input,
+        LanguageTag {
+            language,
+            extlang,
+            script: script.map(str::to_string),
+            region: region.map(str::to_string),
+            variants: to_strings(&variants),
+            extensions,
+            private_use: private_use.unwrap_or_default(),
+        },
+    ))
+}
+
+fn to_strings(subtags: &[&str]) -> Vec<String> {
+    subtags.iter().map(|s| s.to_string()).collect()
+}
+
+/// What could have followed the subtags of a `langtag` that matched only
+/// part of the input.
+fn expected_after(tag: &LanguageTag) -> (&'static str, String) {
+    if !tag.private_use.is_empty() {
+        return ("privateUse", PRIVATE_USE.to_string());
+    }
+    if !tag.extensions.is_empty() {
+        return (
+            "extension",
+            format!(
+                "{}, {} or the private use singleton x",
+                EXTENSION, SINGLETON
+            ),
+        );
+    }
+    let mut expected = Vec::new();
+    if tag.script.is_none() && tag.region.is_none() && tag.variants.is_empty() {
+        if tag.language.len() <= 3 && tag.extlang.len() < 3 {
+            expected.push(EXTLANG);
+        }
+        expected.push(SCRIPT);
+    }
+    if tag.region.is_none() && tag.variants.is_empty() {
+        expected.push(REGION);
+    }
+    expected.extend([VARIANT, SINGLETON]);
+    (
+        "language",
+        format!("{} or the private use singleton x", expected.join(", ")),
+    )
+}
+
+fn error_detail(input: &str, rest: &str, key: &str, expected: &str) -> ParseDetail {
+    let rest = rest.strip_prefix('-').unwrap_or(rest);
+    let parsed = &input[..input.len() - rest.len()];
+    let offset = parsed.chars().count();
+    let subtag = rest.split('-').next().unwrap_or_default();
+    let found = match subtag {
+        "" => format!("Empty subtag at offset {}", offset),
+        subtag => format!("Invalid subtag {} at offset {}", subtag, offset),
+    };
+    ParseDetail {
+        key: key.to_string(),
+        severity: Severity::Error,
+        value: ValueX::ValueString(format!("{}: expected {}", found, expected)),
+        location: Location {
+            start: Some(offset),
+            end: Some(offset + subtag.chars().count()),
+            subtag_index: Some(parsed.matches('-').count()),
+        },
+    }
+}
+
+fn complete<'a, O>(
+    input: &'a str,
+    result: Parsed<'a, O>,
+    expected_after: impl Fn(&O) -> (&'static str, String),
+) -> Result<O, ParseDetail> {
+    match result {
+        Ok(("", parsed)) => Ok(parsed),
+        Ok((rest, parsed)) => {
+            let (key, expected) = expected_after(&parsed);
+            Err(error_detail(input, rest, key, &expected))
+        }
+        Err(nom::Err::Error(e) | nom::Err::Failure(e)) => {
+            Err(error_detail(input, e.input, e.key, e.expected))
+        }
+        Err(nom::Err::Incomplete(_)) => unreachable!("complete parsers never ask for more input"),
+    }
+}
+
+/// Parses the `Language-Tag` production. Grandfathered tags are recognized
+/// first, since the regular ones would otherwise match `langtag`.
+pub(crate) fn parse_language_tag(input: &str) -> Result<Tag, ParseDetail> {
+    if let Some(tag) = grandfathered(input) {
+        return Ok(Tag::Grandfathered(tag));
+    }
+    let tag = match privateuse(input) {
+        Err(nom::Err::Error(_)) => return parse_langtag(input).map(Tag::Language),
+        result => result.map(|(rest, private_use)| {
+            let tag = LanguageTag {
+                language: String::new(),
+                extlang: Vec::new(),
+                script: None,
+                region: None,
+                variants: Vec::new(),
+                extensions: Vec::new(),
+                private_use,
+            };
+            (rest, tag)
+        }),
+    };
+    complete(input, tag, |_| ("privateUse", PRIVATE_USE.to_string())).map(Tag::Language)
+}
+
+/// Parses the `langtag` production alone, as used for the source of a
+/// transformed content extension.
+pub(crate) fn parse_langtag(input: &str) -> Result<LanguageTag, ParseDetail> {
+    complete(input, langtag(input), expected_after)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn error(input: &str) -> String {
+        match parse_language_tag(input).unwrap_err().value {
+            ValueX::ValueString(message) => message,
+            value => panic!("unexpected value {:?}", value),
+        }
+    }
+
+    #[test]
+    fn test_parse_langtag() {
+        let Ok(Tag::Language(tag)) = parse_language_tag("zh-yue-Hant-HK-1996-u-co-pinyin-x-a")
+        else {
+            panic!("expected a langtag");
+        };
+        assert_eq!(tag.language, "zh");
+        assert_eq!(tag.extlang, vec!["yue"]);
+        assert_eq!(tag.script.as_deref(), Some("Hant"));
+        assert_eq!(tag.region.as_deref(), Some("HK"));
+        assert_eq!(tag.variants, vec!["1996"]);
+        assert_eq!(tag.extensions[0].singleton, 'u');
+        assert_eq!(tag.extensions[0].parts, vec!["co", "pinyin"]);
+        assert_eq!(tag.private_use, vec!["a"]);
+    }
+
+    #[test]
+    fn test_parse_privateuse_and_grandfathered() {
+        let Ok(Tag::Language(tag)) = parse_language_tag("X-Whatever") else {
+            panic!("expected a privateuse tag");
+        };
+        assert_eq!(tag.language, "");
+        assert_eq!(tag.private_use, vec!["Whatever"]);
+
+        assert_eq!(
+            parse_language_tag("EN-gb-OED"),
+            Ok(Tag::Grandfathered("en-GB-oed"))
+        );
+        assert_eq!(
+            parse_language_tag("art-lojban"),
+            Ok(Tag::Grandfathered("art-lojban"))
+        );
+    }
+
+    #[test]
+    fn test_parse_reports_offset_subtag_and_expectation() {
+        assert_eq!(
+            error("en-US-a"),
+            "Empty subtag at offset 7: expected an extension subtag of 2-8 letters or digits"
+        );
+        assert_eq!(
+            error("en-US-u-ca-toolongsubtag"),
+            "Invalid subtag toolongsubtag at offset 11: expected an extension subtag of 2-8 letters or digits, an extension singleton or the private use singleton x"
+        );
+        assert_eq!(
+            error("e1-US"),
+            "Invalid subtag e1 at offset 0: expected a language subtag of 2-8 letters"
+        );
+        assert_eq!(
+            error("en--US"),
+            "Empty subtag at offset 3: expected an extlang subtag of 3 letters, a script subtag of 4 letters, a region subtag of 2 letters or 3 digits, a variant subtag of 5-8 letters or digits, or a digit followed by 3 letters or digits, an extension singleton or the private use singleton x"
+        );
+        assert_eq!(
+            error("en-1996-US"),
+            "Invalid subtag US at offset 8: expected a variant subtag of 5-8 letters or digits, or a digit followed by 3 letters or digits, an extension singleton or the private use singleton x"
+        );
+        assert_eq!(
+            error("x-abcdefghi"),
+            "Invalid subtag abcdefghi at offset 2: expected a private use subtag of 1-8 letters or digits"
+        );
+        assert_eq!(parse_language_tag("en-x-").unwrap_err().key, "privateUse");
+    }
+
+    #[test]
+    fn test_parse_error_location() {
+        let location = |input| parse_language_tag(input).unwrap_err().location;
+        assert_eq!(
+            location("en-US-u-ca-toolongsubtag"),
+            Location {
+                start: Some(11),
+                end: Some(24),
+                subtag_index: Some(4),
+            }
+        );
+        assert_eq!(
+            location("en-"),
+            Location {
+                start: Some(3),
+                end: Some(3),
+                subtag_index: Some(1),
+            }
+        );
+    }
+}
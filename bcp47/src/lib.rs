@@ -1,7 +1,41 @@
+// `ParseDetail` is the error type throughout: it goes back to the host as is.
+#![allow(clippy::result_large_err)]
+
+mod cache;
 pub mod codesystem;
+mod collection;
+mod display;
 mod extism;
+mod grammar;
+mod likely;
+mod likely_subtags;
+mod matching;
+mod names;
+mod region;
+mod special;
+mod transformed;
+mod unicode;
+use display::DisplayOptions;
 use extism::*;
-use regex::Regex;
+use grammar::Tag;
+use std::fmt;
+use transformed::TransformedExtension;
+use unicode::UnicodeExtension;
+
+/// The code system of the registry records subtag properties point to.
+const SYSTEM: &str = "urn:ietf:bcp:47";
+
+/// The registry fields read from looked-up records; lookups ask the host
+/// for these and nothing else.
+const REGISTRY_FIELDS: &[&str] = &[
+    "Added",
+    "Deprecated",
+    "Macrolanguage",
+    "Preferred-Value",
+    "Prefix",
+    "Scope",
+    "Suppress-Script",
+];
 
 #[derive(Debug, PartialEq, Clone)]
 pub struct LanguageTag {
@@ -20,10 +54,35 @@ pub struct Extension {
     pub parts: Vec<String>,
 }
 
+impl fmt::Display for LanguageTag {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.language)?;
+        for subtag in self
+            .extlang
+            .iter()
+            .chain(self.script.iter())
+            .chain(self.region.iter())
+            .chain(self.variants.iter())
+        {
+            write!(f, "-{}", subtag)?;
+        }
+        for extension in &self.extensions {
+            write!(f, "-{}-{}", extension.singleton, extension.parts.join("-"))?;
+        }
+        if !self.private_use.is_empty() {
+            // A `privateuse` tag has no language to follow.
+            let separator = if self.language.is_empty() { "" } else { "-" };
+            write!(f, "{}x-{}", separator, self.private_use.join("-"))?;
+        }
+        Ok(())
+    }
+}
+
 type CodeWithDoc<'a> = (String, &'a str, Option<Severity>);
 impl LanguageTag {
-    fn properties(&self) -> Vec<CodeWithDoc> {
-        vec![(self.language.clone(), "language", Some(Severity::Error))]
+    fn properties(&self) -> Vec<CodeWithDoc<'_>> {
+        (!self.language.is_empty())
+            .then(|| (self.language.clone(), "language", Some(Severity::Error)))
             .into_iter()
             .chain(
                 self.extlang
@@ -55,102 +114,891 @@ impl LanguageTag {
             .chain(
                 self.private_use
                     .iter()
-                    .map(|c| (c.clone(), "privateuse", None)),
+                    .map(|c| (c.clone(), "privateUse", None)),
             )
             .collect()
     }
 
-    fn into_concept(
+    /// The macrolanguage of the canonical language, as a coding of its
+    /// registry record. The language is read from the canonical form, so
+    /// `zh-yue-HK` gives the macrolanguage of `yue`; the tag's own language
+    /// record is reused when canonicalizing kept it.
+    fn macrolanguage(
         &self,
-        code: &str,
+        canonical: &LanguageTag,
+        records: &[Option<Concept>],
+        db: &dyn TerminologyDb,
+    ) -> Option<ValueX> {
+        let lookup = |code: String, fields: &[&str]| {
+            db.db_lookup(LookupRequest {
+                code,
+                properties: Some(fields.iter().map(|f| f.to_string()).collect()),
+                designations: false,
+            })
+            .concept
+        };
+        let record = match canonical.language == self.language {
+            true => records.first()?.clone()?,
+            false => lookup(
+                registry_code("language", &canonical.language),
+                &["Macrolanguage"],
+            )?,
+        };
+        let code = registry_code("language", record.registry_value("Macrolanguage")?);
+        let display = lookup(code.clone(), &[]).and_then(|concept| concept.display);
+        Some(registry_coding(code, display))
+    }
+
+    /// Index of the first subtag of each entry of `properties()` among the
+    /// `-`-separated subtags of the tag.
+    fn subtag_indices(&self) -> Vec<usize> {
+        let mut next = 0;
+        let mut private_use = false;
+        self.properties()
+            .into_iter()
+            .map(|(value, kind, _)| {
+                if kind == "privateUse" && !private_use {
+                    // Skip the `x` singleton.
+                    private_use = true;
+                    next += 1;
+                }
+                let index = next;
+                next += value.split('-').count();
+                index
+            })
+            .collect()
+    }
+
+    /// The location of the `nth` subtag of a kind from `properties()`.
+    fn subtag_location(&self, kind: &str, nth: usize) -> Location {
+        self.properties()
+            .into_iter()
+            .zip(self.subtag_indices())
+            .filter(|((_, t, _), _)| *t == kind)
+            .nth(nth)
+            .map_or_else(Location::default, |(_, index)| Location::subtag(index))
+    }
+
+    /// Builds the concept for a parsed tag. Subtags are looked up as given,
+    /// so the tag is expected to be in canonical case already. A well-formed
+    /// check leaves the registry out, and with it validation, extensions and
+    /// the canonical form. The display follows the CLDR locale display
+    /// pattern, naming subtags in the requested language where a name is
+    /// known; properties keep the registry descriptions. With a list of
+    /// `properties`, lookups that only feed properties outside it are left
+    /// out. With an `as_of` date, records are read as the registry stood on
+    /// that date.
+    fn into_concept(
+        self,
+        request: &ParseRequest,
         db: &dyn TerminologyDb,
     ) -> (Option<Concept>, Vec<ParseDetail>) {
-        let mut language_display = None;
-        let mut region_display = None;
-        let mut script_display = None;
+        let code = request.code.as_str();
+        let conformance = request.conformance;
+        let options = DisplayOptions::from(request);
+        let as_of = request.as_of.as_deref();
+        let requested = request.properties.as_deref();
+        let wanted = |code: &str| requested.is_none_or(|p| p.iter().any(|w| w == code));
+        // Notes about a property's coverage only when it was asked for by name.
+        let named_in_request = |code: &str| requested.is_some_and(|p| p.iter().any(|w| w == code));
+        let fields: Vec<&str> = REGISTRY_FIELDS
+            .iter()
+            .copied()
+            .filter(|field| match *field {
+                "Macrolanguage" => wanted("macrolanguage"),
+                "Scope" => wanted("collection"),
+                "Added" => as_of.is_some(),
+                _ => true,
+            })
+            .collect();
+        let mut names = Vec::new();
+        let mut keywords = Vec::new();
         let mut properties = Vec::new();
-        let mut parse_details = Vec::new();
+        let well_formed = conformance == Some(Conformance::WellFormed);
+        let strict = requires_validity(conformance);
+        let records = match well_formed {
+            true => vec![None; self.properties().len()],
+            false => self.lookup_records(db, &fields, request.display_language.is_some()),
+        };
+        let records: Vec<Option<Concept>> = match as_of {
+            Some(as_of) => records
+                .into_iter()
+                .map(|record| record.map(|record| record_as_of(record, as_of)))
+                .collect(),
+            None => records,
+        };
+        let registered = !self.language.is_empty() && records.first().is_some_and(Option::is_some);
+        let is_collection = !self.language.is_empty()
+            && records
+                .first()
+                .and_then(Option::as_ref)
+                .and_then(|record| record.registry_value("Scope"))
+                == Some("collection");
+        let (canonical, canonical_details) = self.canonicalize(&records);
+        let mut parse_details = match well_formed {
+            true => Vec::new(),
+            false => self.validate(&records, strict),
+        };
+        let macrolanguage = match well_formed || !wanted("macrolanguage") {
+            true => None,
+            false => self.macrolanguage(&canonical, &records, db),
+        };
 
-        for (c, t, sev) in self.properties() {
+        for (((c, t, sev), record), index) in self
+            .properties()
+            .into_iter()
+            .zip(records)
+            .zip(self.subtag_indices())
+        {
             if let Some(severity) = sev {
-                let lookup_code = format!("{}-{}", t, c);
-                let lookup_result = db.db_lookup(LookupRequest {
-                    code: lookup_code.clone(),
-                    properties: None,
-                });
-
-                match lookup_result.concept {
+                match record {
                     Some(concept) => {
-                        let display = concept.display.unwrap_or_else(|| c.clone());
+                        let added = as_of.and_then(|as_of| {
+                            concept
+                                .registry_value("Added")
+                                .filter(|added| is_after(added, as_of))
+                        });
+                        if let Some(added) = added {
+                            parse_details.push(ParseDetail {
+                                key: t.to_string(),
+                                severity: if strict { Severity::Error } else { severity },
+                                value: ValueX::ValueString(format!(
+                                    "The {} subtag {} was not valid yet: it was added on {}",
+                                    t, c, added
+                                )),
+                                location: Location::subtag(index),
+                            });
+                        }
+                        if let Some(meaning) = special::meaning(t, &c) {
+                            parse_details.push(ParseDetail {
+                                key: t.to_string(),
+                                severity: Severity::Information,
+                                value: ValueX::ValueString(meaning),
+                                location: Location::subtag(index),
+                            });
+                        }
+                        let name = display::subtag_name(Some(&concept), t, &c, options);
                         properties.push(Property {
                             code: t.to_string(),
-                            value: ValueX::ValueString(display.clone()),
+                            value: registry_coding(concept.code, concept.display),
                         });
-
-                        match t {
-                            "language" => language_display = Some(display),
-                            "region" => region_display = Some(display),
-                            "script" => script_display = Some(display),
-                            _ => {}
-                        }
+                        names.push((t, name));
                     }
                     None => {
+                        names.push((t, display::subtag_name(None, t, &c, options)));
                         properties.push(Property {
                             code: t.to_string(),
-                            value: ValueX::ValueString(c.clone()),
+                            value: registry_coding(registry_code(t, &c), None),
                         });
 
-                        parse_details.push(ParseDetail {
-                            key: t.to_string(),
-                            severity,
-                            value: ValueX::ValueString(format!("Invalid {} subtag: {}", t, c)),
-                        });
+                        if !well_formed {
+                            parse_details.push(ParseDetail {
+                                key: t.to_string(),
+                                severity: if strict { Severity::Error } else { severity },
+                                value: ValueX::ValueString(format!("Invalid {} subtag: {}", t, c)),
+                                location: Location::subtag(index),
+                            });
+                        }
                     }
                 }
+            } else {
+                properties.push(Property {
+                    code: t.to_string(),
+                    value: ValueX::ValueString(c),
+                });
             }
         }
 
-        let parts = [
-            region_display.map(|region| format!("Region: {}", region)),
-            script_display.map(|script| format!("Script: {}", script)),
-            match self.variants.len() {
-                0 => None,
-                _ => Some(format!("Variant: {}", self.variants.join(", "))),
-            },
-        ]
-        .iter()
-        .flatten()
-        .cloned()
-        .collect::<Vec<_>>()
-        .join(", ");
-
-        let display = match language_display {
-            Some(language) => {
-                if !parts.is_empty() {
-                    format!("Language: {} ({})", language, parts)
-                } else {
-                    format!("Language: {}", language)
+        // From the canonical language, so an extlang's record gives it even
+        // when the language subtag has none.
+        if let Some(macrolanguage) = macrolanguage {
+            properties.push(Property {
+                code: "macrolanguage".to_string(),
+                value: macrolanguage,
+            });
+        }
+
+        for (n, extension) in self.extensions.iter().enumerate() {
+            let parsed = match extension.singleton {
+                'u' if !well_formed => UnicodeExtension::parse(&extension.parts).map(|u| {
+                    keywords.extend(display::keyword_names(&u, options));
+                    u.properties()
+                }),
+                't' if !well_formed => TransformedExtension::parse(&extension.parts)
+                    .map(|t| t.properties(db, &|code| strict || wanted(code))),
+                _ => continue,
+            };
+            let details_before = parse_details.len();
+            match parsed {
+                Ok((extension_properties, details)) => {
+                    properties.extend(extension_properties);
+                    parse_details.extend(details);
+                }
+                Err(detail) => parse_details.push(detail),
+            }
+            // Extension details count subtags from the one after the
+            // singleton; make them count from the start of the tag.
+            let first = self.subtag_location("extension", n).subtag_index;
+            for detail in &mut parse_details[details_before..] {
+                detail.location.subtag_index = detail
+                    .location
+                    .subtag_index
+                    .zip(first)
+                    .map(|(index, first)| first + 1 + index);
+            }
+        }
+
+        let named = |kind: &str| -> Vec<String> {
+            names
+                .iter()
+                .filter(|(k, _)| *k == kind)
+                .map(|(_, name)| name.clone())
+                .collect()
+        };
+        let mut qualifiers: Vec<String> = ["script", "region", "variant"]
+            .into_iter()
+            .flat_map(named)
+            .collect();
+        qualifiers.extend(keywords);
+        if !self.private_use.is_empty() {
+            qualifiers.push(display::private_use_name(&self.private_use, options));
+        }
+
+        let canonical_case = self.to_string();
+        parse_details.extend(canonical_details);
+        parse_details.extend(canonical_case_detail(code, &canonical_case));
+        properties.push(Property {
+            code: "canonicalCase".to_string(),
+            value: ValueX::ValueString(canonical_case),
+        });
+        if !well_formed {
+            let extlang_form = match wanted("extlangForm") {
+                true => canonical.extlang_form(db),
+                false => None,
+            };
+            if let Some(extlang_form) = extlang_form {
+                properties.push(Property {
+                    code: "extlangForm".to_string(),
+                    value: ValueX::ValueString(extlang_form.to_string()),
+                });
+            }
+            properties.push(Property {
+                code: "canonical".to_string(),
+                value: ValueX::ValueString(canonical.to_string()),
+            });
+            // Only registered languages are worth a note; private use and
+            // special languages never have likely subtags.
+            let registered = registered
+                && special::private_use_range("language", &canonical.language).is_none()
+                && special::meaning("language", &canonical.language).is_none();
+            match likely::maximize(&canonical) {
+                Some(maximized) => properties.push(Property {
+                    code: "maximized".to_string(),
+                    value: ValueX::ValueString(maximized.to_string()),
+                }),
+                None if registered
+                    && (named_in_request("maximized") || named_in_request("minimized")) =>
+                {
+                    parse_details.push(ParseDetail {
+                        key: "maximized".to_string(),
+                        severity: Severity::Information,
+                        value: ValueX::ValueString(format!(
+                            "CLDR has no likely subtags for {}",
+                            canonical.language
+                        )),
+                        location: Location::default(),
+                    })
                 }
+                _ => {}
+            }
+            if let Some(minimized) = likely::minimize(&canonical) {
+                properties.push(Property {
+                    code: "minimized".to_string(),
+                    value: ValueX::ValueString(minimized.to_string()),
+                });
+            }
+            let collections = collection::collections(&canonical.language);
+            // Collection membership is only known for the families in the
+            // table, so say when a language or collection falls outside it.
+            let uncovered = match is_collection {
+                true => (!collection::covers(&canonical.language)).then(|| {
+                    format!(
+                        "The languages in collection {} are not known",
+                        canonical.language
+                    )
+                }),
+                false => (registered && collections.is_empty()).then(|| {
+                    format!(
+                        "Collection membership of {} is not known",
+                        canonical.language
+                    )
+                }),
+            };
+            if let Some(message) = uncovered.filter(|_| named_in_request("collection")) {
+                parse_details.push(ParseDetail {
+                    key: "collection".to_string(),
+                    severity: Severity::Information,
+                    value: ValueX::ValueString(message),
+                    location: Location::default(),
+                });
             }
-            None => format!("Language tag: {}", code),
+            properties.extend(collections.into_iter().map(|collection| Property {
+                code: "collection".to_string(),
+                value: registry_coding(registry_code("language", collection), None),
+            }));
+            if let Some(region) = &canonical.region {
+                properties.extend(
+                    region::ancestors(region)
+                        .into_iter()
+                        .map(|ancestor| Property {
+                            code: "regionContainment".to_string(),
+                            value: registry_coding(registry_code("region", ancestor), None),
+                        }),
+                );
+            }
+        }
+
+        // CLDR names `zh-yue` as `yue`, so the extlang names the language.
+        let display = match named("extlang").pop().or_else(|| named("language").pop()) {
+            Some(language) => display::locale_display_name(&language, &qualifiers, options),
+            None => code.to_string(),
         };
         (
             Some(Concept {
                 code: code.to_string(),
                 properties,
                 display: Some(display),
-                ..Default::default()
+                designations: Vec::new(),
             }),
             parse_details,
         )
     }
+
+    /// Registry records for the subtags listed by `properties()`, in the same
+    /// order. Extensions and private use subtags have no records.
+    fn lookup_records(
+        &self,
+        db: &dyn TerminologyDb,
+        fields: &[&str],
+        designations: bool,
+    ) -> Vec<Option<Concept>> {
+        self.properties()
+            .into_iter()
+            .map(|(c, t, sev)| sev.and_then(|_| lookup_record(db, t, &c, fields, designations)))
+            .collect()
+    }
+
+    /// Registry validity checks that go beyond each subtag being registered.
+    /// Prefix mismatches are warnings unless `strict` validity is required.
+    fn validate(&self, records: &[Option<Concept>], strict: bool) -> Vec<ParseDetail> {
+        let prefix_severity = if strict {
+            Severity::Error
+        } else {
+            Severity::Warning
+        };
+        let mut details = Vec::new();
+        if self.extlang.len() > 1 {
+            details.push(ParseDetail {
+                key: "extlang".to_string(),
+                severity: Severity::Error,
+                value: ValueX::ValueString(format!(
+                    "At most one extlang subtag is permitted: {}",
+                    self.extlang.join("-")
+                )),
+                location: self.subtag_location("extlang", 1),
+            });
+        }
+
+        for (i, variant) in self.variants.iter().enumerate() {
+            if self.variants[..i].contains(variant) {
+                details.push(ParseDetail {
+                    key: "variant".to_string(),
+                    severity: Severity::Error,
+                    value: ValueX::ValueString(format!(
+                        "Variant subtag {} appears more than once",
+                        variant
+                    )),
+                    location: self.subtag_location("variant", i),
+                });
+            }
+        }
+        for (i, extension) in self.extensions.iter().enumerate() {
+            if self.extensions[..i]
+                .iter()
+                .any(|e| e.singleton == extension.singleton)
+            {
+                details.push(ParseDetail {
+                    key: "extension".to_string(),
+                    severity: Severity::Error,
+                    value: ValueX::ValueString(format!(
+                        "Extension singleton {} appears more than once",
+                        extension.singleton
+                    )),
+                    location: self.subtag_location("extension", i),
+                });
+            }
+        }
+
+        if let Some(script) = self.suppressed_script(records) {
+            details.push(ParseDetail {
+                key: "script".to_string(),
+                severity: Severity::Warning,
+                value: ValueX::ValueString(format!(
+                    "Script subtag {} is redundant: it is the Suppress-Script of language {}",
+                    script, self.language
+                )),
+                location: self.subtag_location("script", 0),
+            });
+        }
+
+        for (((subtag, kind, _), record), index) in self
+            .properties()
+            .into_iter()
+            .zip(records)
+            .zip(self.subtag_indices())
+        {
+            let Some(record) = record else {
+                continue;
+            };
+            if kind == "variant" {
+                let prefixes: Vec<_> = record.registry_values("Prefix").collect();
+                if !prefixes.is_empty()
+                    && !prefixes
+                        .iter()
+                        .any(|prefix| self.variant_has_prefix(&subtag, prefix))
+                {
+                    details.push(ParseDetail {
+                        key: kind.to_string(),
+                        severity: prefix_severity.clone(),
+                        value: ValueX::ValueString(format!(
+                            "Variant subtag {} requires one of the prefixes: {}",
+                            subtag,
+                            prefixes.join(", ")
+                        )),
+                        location: Location::subtag(index),
+                    });
+                }
+            }
+            if kind == "extlang" {
+                if let Some(prefix) = record.registry_value("Prefix") {
+                    if prefix != self.language {
+                        details.push(ParseDetail {
+                            key: kind.to_string(),
+                            severity: prefix_severity.clone(),
+                            value: ValueX::ValueString(format!(
+                                "Extlang subtag {} requires the prefix {}",
+                                subtag, prefix
+                            )),
+                            location: Location::subtag(index),
+                        });
+                    }
+                }
+            }
+        }
+        details
+    }
+
+    /// Whether a registered `Prefix` such as `sl-rozaj` applies to a variant:
+    /// the prefix starts with the primary language and each of its other
+    /// subtags appears in the tag before the variant.
+    fn variant_has_prefix(&self, variant: &str, prefix: &str) -> bool {
+        let preceding: Vec<&str> = self
+            .extlang
+            .iter()
+            .chain(self.script.iter())
+            .chain(self.region.iter())
+            .chain(self.variants.iter().take_while(|v| *v != variant))
+            .map(String::as_str)
+            .collect();
+        let mut prefix = prefix.split('-');
+        prefix.next() == Some(self.language.as_str())
+            && prefix.all(|subtag| preceding.contains(&subtag))
+    }
+
+    /// The RFC 5646 §4.5 "extlang form" of a canonical tag: a language that
+    /// is also registered as an extlang is preceded by its prefix, so `yue-HK`
+    /// becomes `zh-yue-HK`.
+    fn extlang_form(&self, db: &dyn TerminologyDb) -> Option<LanguageTag> {
+        if self.language.len() != 3 || !self.extlang.is_empty() {
+            return None;
+        }
+        let record = db
+            .db_lookup(format!("extlang-{}", self.language).as_str().into())
+            .concept?;
+        let prefix = record.registry_value("Prefix")?;
+        Some(LanguageTag {
+            language: prefix.to_string(),
+            extlang: vec![self.language.clone()],
+            ..self.clone()
+        })
+    }
+
+    /// Produces the RFC 5646 §4.5 canonical form: extensions ordered by
+    /// singleton and subtags replaced by their registered `Preferred-Value`,
+    /// with a warning for each deprecated subtag giving its deprecation date
+    /// and replacement, if any.
+    fn canonicalize(&self, records: &[Option<Concept>]) -> (LanguageTag, Vec<ParseDetail>) {
+        let mut canonical = LanguageTag {
+            extlang: Vec::new(),
+            script: None,
+            region: None,
+            variants: Vec::new(),
+            ..self.clone()
+        };
+        canonical.extensions.sort_by_key(|e| e.singleton);
+        let mut details = Vec::new();
+
+        for (((subtag, kind, _), record), index) in self
+            .properties()
+            .into_iter()
+            .zip(records)
+            .zip(self.subtag_indices())
+        {
+            let preferred = record
+                .as_ref()
+                .and_then(|r| r.registry_value("Preferred-Value"));
+            let deprecated = record.as_ref().and_then(|r| r.registry_value("Deprecated"));
+            if let Some(deprecated) = deprecated {
+                details.push(ParseDetail {
+                    key: kind.to_string(),
+                    severity: Severity::Warning,
+                    value: ValueX::ValueString(match preferred {
+                        Some(preferred) => format!(
+                            "Deprecated {} subtag {} (since {}) replaced by {}",
+                            kind, subtag, deprecated, preferred
+                        ),
+                        None => format!(
+                            "Deprecated {} subtag {} (since {}) has no replacement",
+                            kind, subtag, deprecated
+                        ),
+                    }),
+                    location: Location::subtag(index),
+                });
+            }
+            // An extlang under the wrong prefix (`qaa-yue`) is not the
+            // registered one, so it is left for `validate` to report rather
+            // than rewritten.
+            let preferred = preferred.filter(|_| {
+                kind != "extlang"
+                    || record
+                        .as_ref()
+                        .and_then(|r| r.registry_value("Prefix"))
+                        .is_none_or(|prefix| prefix == self.language)
+            });
+            let value = preferred.map_or(subtag, str::to_string);
+            match kind {
+                "language" => canonical.language = value,
+                "extlang" => match preferred {
+                    // The extlang's preferred value replaces the primary
+                    // language, turning `zh-yue` into `yue`.
+                    Some(_) => canonical.language = value,
+                    None => canonical.extlang.push(value),
+                },
+                "script" => canonical.script = Some(value),
+                "region" => canonical.region = Some(value),
+                "variant" => canonical.variants.push(value),
+                _ => {}
+            }
+        }
+        if self.suppressed_script(records).is_some() {
+            canonical.script = None;
+        }
+        (canonical, details)
+    }
+
+    /// The script subtag, when it matches the `Suppress-Script` of the
+    /// language and so adds nothing (`en-Latn`).
+    fn suppressed_script(&self, records: &[Option<Concept>]) -> Option<&str> {
+        let language = records.first()?.as_ref()?;
+        let script = self.script.as_deref()?;
+        (language.registry_value("Suppress-Script") == Some(script)).then_some(script)
+    }
+
+    /// Applies the RFC 5646 §2.1.1 casing conventions: lowercase language,
+    /// extlang, variants, extensions and private use; titlecase script;
+    /// uppercase region.
+    fn canonical_case(&self) -> LanguageTag {
+        let lower = |s: &String| s.to_ascii_lowercase();
+        LanguageTag {
+            language: lower(&self.language),
+            extlang: self.extlang.iter().map(lower).collect(),
+            script: self.script.as_ref().map(|s| {
+                let mut script = s.to_ascii_lowercase();
+                script[..1].make_ascii_uppercase();
+                script
+            }),
+            region: self.region.as_ref().map(|r| r.to_ascii_uppercase()),
+            variants: self.variants.iter().map(lower).collect(),
+            extensions: self
+                .extensions
+                .iter()
+                .map(|e| Extension {
+                    singleton: e.singleton.to_ascii_lowercase(),
+                    parts: e.parts.iter().map(lower).collect(),
+                })
+                .collect(),
+            private_use: self.private_use.iter().map(lower).collect(),
+        }
+    }
+
+    /// A tag subsumes another when every subtag it specifies is also present
+    /// in the other: `en` subsumes `en-US`, `zh-Hant` subsumes `zh-Hant-TW`
+    /// and `sl-rozaj` subsumes `sl-rozaj-biske`. A region also subsumes the
+    /// regions it contains, so `es-419` subsumes `es-MX`. Both tags are
+    /// expected to be in canonical case.
+    fn subsumes(&self, other: &LanguageTag) -> bool {
+        fn optional(a: &Option<String>, b: &Option<String>) -> bool {
+            a.is_none() || a == b
+        }
+
+        self.language == other.language
+            && other.extlang.starts_with(&self.extlang)
+            && optional(&self.script, &other.script)
+            && match (&self.region, &other.region) {
+                (None, _) => true,
+                (Some(a), Some(b)) => a == b || region::contains(a, b),
+                (Some(_), None) => false,
+            }
+            && other.variants.starts_with(&self.variants)
+            && self.extensions.iter().all(|e| other.extensions.contains(e))
+            && other.private_use.starts_with(&self.private_use)
+    }
+}
+
+/// Whether registry problems make the tag fail the requested conformance,
+/// rather than being reported as warnings.
+fn requires_validity(conformance: Option<Conformance>) -> bool {
+    matches!(
+        conformance,
+        Some(Conformance::Valid | Conformance::Canonical)
+    )
+}
+
+/// Fills in the offsets of details that locate a subtag only by index.
+fn locate_details(code: &str, details: &mut [ParseDetail]) {
+    let mut start = 0;
+    let spans: Vec<(usize, usize)> = code
+        .split('-')
+        .map(|subtag| {
+            let span = (start, start + subtag.chars().count());
+            start = span.1 + 1;
+            span
+        })
+        .collect();
+    for location in details.iter_mut().map(|d| &mut d.location) {
+        if let Some(&(start, end)) = location.subtag_index.and_then(|i| spans.get(i)) {
+            location.start = location.start.or(Some(start));
+            location.end = location.end.or(Some(end));
+        }
+    }
+}
+
+fn canonical_case_detail(code: &str, canonical_case: &str) -> Option<ParseDetail> {
+    (code != canonical_case).then(|| ParseDetail {
+        key: "canonicalCase".to_string(),
+        severity: Severity::Information,
+        value: ValueX::ValueString(format!(
+            "Tag is not in canonical case; expected {}",
+            canonical_case
+        )),
+        location: Location::default(),
+    })
+}
+
+/// Applies a whole-tag registry record (`grandfathered-*` or `redundant-*`)
+/// to a concept: its description, deprecation date and `Preferred-Value`.
+fn apply_tag_record(
+    record: &Concept,
+    concept: &mut Concept,
+    details: &mut Vec<ParseDetail>,
+    options: DisplayOptions,
+) {
+    let (kind, tag) = record.code.split_once('-').unwrap_or(("", &record.code));
+    let name = options
+        .language
+        .and_then(|language| display::localized_name(record, language));
+    if let Some(display) = name.or_else(|| record.display.clone()) {
+        concept.display = Some(display);
+    }
+    let preferred_value = record.registry_value("Preferred-Value");
+    if let Some(deprecated) = record.registry_value("Deprecated") {
+        concept.properties.push(Property {
+            code: "deprecated".to_string(),
+            value: ValueX::ValueDateTime(deprecated.to_string()),
+        });
+        details.push(ParseDetail {
+            key: "deprecated".to_string(),
+            severity: Severity::Warning,
+            value: ValueX::ValueString(match preferred_value {
+                Some(preferred) => format!(
+                    "Tag {} ({}) was deprecated on {}; use {}",
+                    tag, kind, deprecated, preferred
+                ),
+                None => format!("Tag {} ({}) was deprecated on {}", tag, kind, deprecated),
+            }),
+            location: Location::default(),
+        });
+    }
+    if let Some(preferred) = preferred_value {
+        concept.properties.push(Property {
+            code: "preferredValue".to_string(),
+            value: ValueX::ValueString(preferred.to_string()),
+        });
+        if let Some(canonical) = concept
+            .properties
+            .iter_mut()
+            .find(|p| p.code == "canonical")
+        {
+            canonical.value = ValueX::ValueString(preferred.to_string());
+        }
+    }
+}
+
+/// The registry record of a subtag, reading `fields` from it, and its
+/// designations if asked.
+fn lookup_record(
+    db: &dyn TerminologyDb,
+    kind: &str,
+    subtag: &str,
+    fields: &[&str],
+    designations: bool,
+) -> Option<Concept> {
+    let lookup = |code: String| {
+        db.db_lookup(LookupRequest {
+            code,
+            properties: Some(fields.iter().map(|f| f.to_string()).collect()),
+            designations,
+        })
+        .concept
+    };
+    // The registry has one record per private use range (`qaa..qtz`), not
+    // one per subtag in it; a subtag CLDR gives a meaning of its own (`XK`)
+    // keeps it rather than the range's.
+    lookup(registry_code(kind, subtag))
+        .or_else(|| match special::is_special(kind, subtag) {
+            true => special::record(kind, subtag),
+            false => special::private_use_range(kind, subtag)
+                .and_then(|range| lookup(registry_code(kind, &range))),
+        })
+        .or_else(|| special::record(kind, subtag))
+}
+
+/// Whether a registry date falls after `as_of`, comparing calendar dates so
+/// that `2009-07-29` and `2009-07-29T00:00:00Z` are the same day.
+fn is_after(date: &str, as_of: &str) -> bool {
+    let day = |d: &'_ str| d.get(..10).unwrap_or(d).to_string();
+    day(date) > day(as_of)
+}
+
+/// An Error detail unless `as_of` is a `YYYY-MM-DD` date, alone or starting
+/// a dateTime; only the date is compared with registry dates.
+fn invalid_as_of(as_of: &str) -> Option<ParseDetail> {
+    let bytes = as_of.as_bytes();
+    let well_formed = bytes.len() >= 10
+        && bytes[..10].iter().enumerate().all(|(i, b)| match i {
+            4 | 7 => *b == b'-',
+            _ => b.is_ascii_digit(),
+        })
+        && matches!(as_of[5..7].parse::<u8>(), Ok(1..=12))
+        && matches!(as_of[8..10].parse::<u8>(), Ok(1..=31))
+        && bytes.get(10).is_none_or(|b| *b == b'T');
+    (!well_formed).then(|| ParseDetail {
+        key: "asOf".to_string(),
+        severity: Severity::Error,
+        value: ValueX::ValueString(format!("The as-of date {} is not a YYYY-MM-DD date", as_of)),
+        location: Location::default(),
+    })
+}
+
+/// A detail when a grandfathered or redundant tag record was added after
+/// `as_of`, like the one for subtags.
+fn tag_added_detail(
+    record: &Concept,
+    as_of: Option<&str>,
+    conformance: Option<Conformance>,
+) -> Option<ParseDetail> {
+    let added = record
+        .registry_value("Added")
+        .filter(|added| as_of.is_some_and(|as_of| is_after(added, as_of)))?;
+    let (kind, tag) = record.code.split_once('-').unwrap_or(("", &record.code));
+    Some(ParseDetail {
+        key: kind.to_string(),
+        severity: if requires_validity(conformance) {
+            Severity::Error
+        } else {
+            Severity::Warning
+        },
+        value: ValueX::ValueString(format!(
+            "Tag {} ({}) was not valid yet: it was added on {}",
+            tag, kind, added
+        )),
+        location: Location::default(),
+    })
+}
+
+/// A record as the registry stood on `as_of`: a later deprecation, and the
+/// replacement that came with it, had not happened yet.
+fn record_as_of(mut record: Concept, as_of: &str) -> Concept {
+    if record
+        .registry_value("Deprecated")
+        .is_some_and(|deprecated| is_after(deprecated, as_of))
+    {
+        record
+            .properties
+            .retain(|p| p.code != "Deprecated" && p.code != "Preferred-Value");
+    }
+    record
+}
+
+/// The registry code of a subtag, e.g. `language-en` or `extlang-yue`.
+fn registry_code(kind: &str, subtag: &str) -> String {
+    format!("{}-{}", kind.to_lowercase(), subtag)
+}
+
+/// A subtag property value pointing at its registry record. Subtags missing
+/// from the registry keep the code they would have, without a display.
+pub(crate) fn registry_coding(code: String, display: Option<String>) -> ValueX {
+    ValueX::ValueCoding(Coding {
+        system: Some(SYSTEM.to_string()),
+        code: Some(code),
+        display,
+    })
+}
+
+impl ValueX {
+    fn as_str(&self) -> Option<&str> {
+        match self {
+            ValueX::ValueString(s) | ValueX::ValueDateTime(s) | ValueX::ValueCode(s) => Some(s),
+            _ => None,
+        }
+    }
+}
+
+impl Concept {
+    /// Values of a registry field that may repeat, such as `Prefix`.
+    fn registry_values<'a>(&'a self, field: &'a str) -> impl Iterator<Item = &'a str> {
+        self.properties
+            .iter()
+            .filter(move |p| p.code == field)
+            .filter_map(|p| p.value.as_str())
+    }
+
+    /// First value of a registry field such as `Preferred-Value` or
+    /// `Deprecated`.
+    fn registry_value(&self, field: &str) -> Option<&str> {
+        self.properties
+            .iter()
+            .find(|p| p.code == field)
+            .and_then(|p| p.value.as_str())
+    }
 }
 
 impl From<&str> for LookupRequest {
     fn from(code: &str) -> Self {
         LookupRequest {
             code: code.to_string(),
-            properties: None,
+            properties: Some(REGISTRY_FIELDS.iter().map(|f| f.to_string()).collect()),
+            designations: false,
         }
     }
 }
@@ -179,19 +1027,131 @@ where
     T: TerminologyDb,
 {
     fn parse(&self, request: ParseRequest) -> ParseResponse {
-        self.parse_language_tag(&request.code)
-            .map(|tag| {
-                let (concept, details) = tag.into_concept(&request.code, &self.db);
+        let conformance = request.conformance;
+        let options = DisplayOptions::from(&request);
+        if let Some(detail) = request.as_of.as_deref().and_then(invalid_as_of) {
+            return ParseResponse {
+                concept: None,
+                details: vec![detail],
+            };
+        }
+        let mut response = match grammar::parse_language_tag(&request.code) {
+            Ok(Tag::Grandfathered(tag)) => self.parse_grandfathered(&request, tag),
+            Ok(Tag::Language(tag)) => {
+                let tag = tag.canonical_case();
+                let redundant = match conformance {
+                    Some(Conformance::WellFormed) => None,
+                    _ => self.lookup_redundant(&tag, request.display_language.is_some()),
+                };
+                let (mut concept, mut details) = tag.into_concept(&request, &self.db);
+                let redundant = redundant.map(|record| match &request.as_of {
+                    Some(as_of) => record_as_of(record, as_of),
+                    None => record,
+                });
+                if let (Some(concept), Some(record)) = (concept.as_mut(), redundant) {
+                    details.extend(tag_added_detail(
+                        &record,
+                        request.as_of.as_deref(),
+                        conformance,
+                    ));
+                    apply_tag_record(&record, concept, &mut details, options);
+                }
+                locate_details(&request.code, &mut details);
                 ParseResponse { concept, details }
-            })
-            .unwrap_or_else(|detail| ParseResponse {
+            }
+            Err(detail) => ParseResponse {
                 concept: None,
                 details: vec![detail],
+            },
+        };
+        if conformance == Some(Conformance::Canonical) {
+            let canonical = response.concept.as_ref().and_then(|concept| {
+                concept
+                    .properties
+                    .iter()
+                    .find(|p| p.code == "canonical")
+                    .and_then(|p| p.value.as_str())
+            });
+            if let Some(canonical) = canonical.filter(|c| *c != request.code) {
+                response.details.push(ParseDetail {
+                    key: "canonical".to_string(),
+                    severity: Severity::Error,
+                    value: ValueX::ValueString(format!(
+                        "Tag is not in canonical form; expected {}",
+                        canonical
+                    )),
+                    location: Location::default(),
+                });
+            }
+        }
+        if let (Some(properties), Some(concept)) = (&request.properties, &mut response.concept) {
+            concept.properties.retain(|p| properties.contains(&p.code));
+        }
+        response
+    }
+
+    fn parse_batch(&self, request: ParseBatchRequest) -> ParseBatchResponse {
+        let guest = Guest::new(cache::CachingDb::new(&self.db));
+        ParseBatchResponse {
+            responses: request
+                .requests
+                .into_iter()
+                .map(|request| guest.parse(request))
+                .collect(),
+        }
+    }
+
+    fn subsumes(&self, request: SubsumesRequest) -> SubsumesResponse {
+        let subsumes = match (
+            self.canonical_tag(&request.ancestor),
+            self.canonical_tag(&request.descendant),
+        ) {
+            (Some(ancestor), Some(descendant)) => {
+                ancestor.subsumes(&descendant)
+                    || (request.modes.contains(&SubsumesMode::Macrolanguage)
+                        && self.encompasses(&ancestor, &descendant))
+                    || (request.modes.contains(&SubsumesMode::Collection)
+                        && collection::includes(&ancestor.language, &descendant.language)
+                        && ancestor.subsumes(&LanguageTag {
+                            language: ancestor.language.clone(),
+                            ..descendant.clone()
+                        }))
+                    || (request.modes.contains(&SubsumesMode::LikelySubtags) && {
+                        let descendant = likely::maximize(&descendant).unwrap_or(descendant);
+                        ancestor.subsumes(&descendant)
+                            || likely::maximize(&ancestor)
+                                .is_some_and(|ancestor| ancestor.subsumes(&descendant))
+                    })
+            }
+            _ => false,
+        };
+        SubsumesResponse { subsumes }
+    }
+
+    fn filter(&self, request: FilterRequest) -> FilterResponse {
+        let (tags, details) = matchable_tags(&request.tags);
+        let tags = tags
+            .into_iter()
+            .filter(|(_, tag)| {
+                request.ranges.iter().any(|range| match request.extended {
+                    true => matching::extended_filter(range, tag),
+                    false => matching::basic_filter(range, tag),
+                })
             })
+            .map(|(code, _)| code.to_string())
+            .collect();
+        FilterResponse { tags, details }
     }
 
-    fn subsumes(&self, _req: SubsumesRequest) -> SubsumesResponse {
-        todo!()
+    fn lookup(&self, request: RangeLookupRequest) -> RangeLookupResponse {
+        let (tags, details) = matchable_tags(&request.tags);
+        let (codes, tags): (Vec<_>, Vec<_>) = tags.into_iter().unzip();
+        let ranges: Vec<&str> = request.ranges.iter().map(String::as_str).collect();
+        let tag = match matching::lookup(&ranges, &tags) {
+            Some(index) => Some(codes[index].to_string()),
+            None => request.default,
+        };
+        RangeLookupResponse { tag, details }
     }
 
     fn metadata(&self) -> String {
@@ -199,155 +1159,166 @@ where
     }
 }
 
+/// Parses the tags to match language ranges against. Grandfathered tags have
+/// no subtag structure to match and are left out, as are tags that aren't
+/// well-formed, each with a detail saying so.
+fn matchable_tags(codes: &[String]) -> (Vec<(&str, LanguageTag)>, Vec<ParseDetail>) {
+    let mut tags = Vec::new();
+    let mut details = Vec::new();
+    for code in codes {
+        let message = match grammar::parse_language_tag(code) {
+            Ok(Tag::Language(tag)) => {
+                tags.push((code.as_str(), tag));
+                continue;
+            }
+            Ok(Tag::Grandfathered(_)) => {
+                format!("Grandfathered tag {} is not matched against ranges", code)
+            }
+            Err(detail) => format!(
+                "Tag {} is not matched against ranges: {}",
+                code,
+                detail.value.as_str().unwrap_or_default()
+            ),
+        };
+        details.push(ParseDetail {
+            key: "tag".to_string(),
+            severity: Severity::Warning,
+            value: ValueX::ValueString(message),
+            location: Location::default(),
+        });
+    }
+    (tags, details)
+}
+
 impl<T> Guest<T>
 where
     T: TerminologyDb,
 {
-    fn parse_language_tag(&self, input: &str) -> Result<LanguageTag, ParseDetail> {
-        let re = Regex::new(r"-").unwrap();
-        let parts: Vec<&str> = re.split(input).collect();
-
-        let language: String;
-        let mut extlang = Vec::new();
-        let mut script = None;
-        let mut region = None;
-        let mut variants = Vec::new();
-        let mut extensions = Vec::new();
-        let mut private_use = Vec::new();
-
-        let mut i = 0;
-
-        // Language
-        if i < parts.len()
-            && parts[i].len() >= 2
-            && parts[i].len() <= 8
-            && parts[i].chars().all(|c| c.is_ascii_alphanumeric())
-        {
-            language = parts[i].to_string();
-            i += 1;
-        } else {
-            return Err(ParseDetail {
-                key: "language".to_string(),
-                severity: Severity::Error,
-                value: ValueX::ValueString(format!("Invalid language subtag: {}", parts[i])),
-            });
-        }
-
-        // Extlang
-        while i < parts.len()
-            && parts[i].len() == 3
-            && parts[i].chars().all(|c| c.is_ascii_alphabetic())
-            && extlang.len() < 3
-        {
-            extlang.push(parts[i].to_string());
-            i += 1;
-        }
-
-        // Script
-        if i < parts.len()
-            && parts[i].len() == 4
-            && parts[i].chars().all(|c| c.is_ascii_alphabetic())
-        {
-            script = Some(parts[i].to_string());
-            i += 1;
-        }
-
-        // Region
-        if i < parts.len()
-            && ((parts[i].len() == 2 && parts[i].chars().all(|c| c.is_ascii_alphabetic()))
-                || (parts[i].len() == 3 && parts[i].chars().all(|c| c.is_ascii_digit())))
-        {
-            region = Some(parts[i].to_string());
-            i += 1;
-        }
-
-        // Variants
-        while i < parts.len()
-            && ((parts[i].len() >= 5
-                && parts[i].len() <= 8
-                && parts[i].chars().all(|c| c.is_ascii_alphanumeric()))
-                || (parts[i].len() == 4 && parts[i].chars().next().unwrap().is_ascii_digit()))
-            && variants.len() < 5
-        {
-            variants.push(parts[i].to_string());
-            i += 1;
-        }
-
-        // Extensions
-        while i < parts.len()
-            && parts[i].len() == 1
-            && parts[i].chars().next().unwrap().is_ascii_alphabetic()
-            && parts[i] != "x"
-        {
-            let singleton = parts[i].chars().next().unwrap();
-            let mut extension_parts = Vec::new();
-            i += 1;
-            while i < parts.len()
-                && parts[i].len() >= 2
-                && parts[i].len() <= 8
-                && parts[i].chars().all(|c| c.is_ascii_alphanumeric())
-            {
-                extension_parts.push(parts[i].to_string());
-                i += 1;
-            }
-            if !extension_parts.is_empty() {
-                extensions.push(Extension {
-                    singleton,
-                    parts: extension_parts,
-                });
-            } else {
-                return Err(ParseDetail {
-                    key: "extension".to_string(),
-                    severity: Severity::Error,
-                    value: ValueX::ValueString(format!(
-                        "Invalid extension subtag: {}",
-                        parts[i - 1]
-                    )),
-                });
+    /// Grandfathered tags are only meaningful as a whole, so they're looked
+    /// up in the registry instead of being decomposed into subtags.
+    fn parse_grandfathered(&self, request: &ParseRequest, tag: &str) -> ParseResponse {
+        let code = request.code.as_str();
+        let conformance = request.conformance;
+        let options = DisplayOptions::from(request);
+        let mut details: Vec<ParseDetail> = canonical_case_detail(code, tag).into_iter().collect();
+        let mut concept = Concept {
+            code: code.to_string(),
+            display: Some(code.to_string()),
+            properties: vec![
+                Property {
+                    code: "canonicalCase".to_string(),
+                    value: ValueX::ValueString(tag.to_string()),
+                },
+                Property {
+                    code: "canonical".to_string(),
+                    value: ValueX::ValueString(tag.to_string()),
+                },
+            ],
+            designations: Vec::new(),
+        };
+        let record = match conformance {
+            Some(Conformance::WellFormed) => None,
+            _ => {
+                self.db
+                    .db_lookup(LookupRequest {
+                        designations: request.display_language.is_some(),
+                        ..format!("grandfathered-{}", tag).as_str().into()
+                    })
+                    .concept
             }
         }
-
-        // Private Use
-        if i < parts.len() && parts[i] == "x" {
-            i += 1;
-            private_use = Vec::new();
-            while i < parts.len() {
-                if parts[i].len() >= 1
-                    && parts[i].len() <= 8
-                    && parts[i].chars().all(|c| c.is_ascii_alphanumeric())
-                {
-                    private_use.push(parts[i].to_string());
-                    i += 1;
-                } else {
-                    return Err(ParseDetail {
-                        key: "privateUse".to_string(),
-                        severity: Severity::Error,
-                        value: ValueX::ValueString(format!(
-                            "Invalid private use subtag: {}",
-                            parts[i]
-                        )),
-                    });
-                }
+        .map(|record| match &request.as_of {
+            Some(as_of) => record_as_of(record, as_of),
+            None => record,
+        });
+        match record {
+            Some(record) => {
+                details.extend(tag_added_detail(
+                    &record,
+                    request.as_of.as_deref(),
+                    conformance,
+                ));
+                apply_tag_record(&record, &mut concept, &mut details, options);
             }
+            None if conformance == Some(Conformance::WellFormed) => {}
+            None => details.push(ParseDetail {
+                key: "grandfathered".to_string(),
+                severity: if requires_validity(conformance) {
+                    Severity::Error
+                } else {
+                    Severity::Warning
+                },
+                value: ValueX::ValueString(format!(
+                    "Grandfathered tag {} is not in the registry",
+                    tag
+                )),
+                location: Location::default(),
+            }),
+        }
+        ParseResponse {
+            concept: Some(concept),
+            details,
         }
+    }
 
-        if i < parts.len() {
-            return Err(ParseDetail {
-                key: "language".to_string(),
-                severity: Severity::Error,
-                value: ValueX::ValueString(format!("Invalid language tag: {}", input)),
-            });
+    /// Redundant tags are well-formed but also have a registry record of
+    /// their own, e.g. `zh-Hant` or the deprecated `sgn-BR`.
+    fn lookup_redundant(&self, tag: &LanguageTag, designations: bool) -> Option<Concept> {
+        let code = tag.to_string();
+        if !code.contains('-') || !tag.extensions.is_empty() || !tag.private_use.is_empty() {
+            return None;
         }
+        self.db
+            .db_lookup(LookupRequest {
+                designations,
+                ..format!("redundant-{}", code).as_str().into()
+            })
+            .concept
+    }
 
-        Ok(LanguageTag {
-            language,
-            extlang,
-            script,
-            region,
-            variants,
-            extensions,
-            private_use,
-        })
+    /// Whether the ancestor's language is the macrolanguage of the
+    /// descendant's, and the ancestor subsumes the descendant once its
+    /// language is replaced by that macrolanguage.
+    fn encompasses(&self, ancestor: &LanguageTag, descendant: &LanguageTag) -> bool {
+        let Some(record) = self
+            .db
+            .db_lookup(format!("language-{}", descendant.language).as_str().into())
+            .concept
+        else {
+            return false;
+        };
+        record.registry_value("Macrolanguage") == Some(ancestor.language.as_str())
+            && ancestor.subsumes(&LanguageTag {
+                language: ancestor.language.clone(),
+                ..descendant.clone()
+            })
+    }
+
+    /// Parses a tag into its canonical form, following the `Preferred-Value`
+    /// of grandfathered and redundant tags before canonicalizing subtags.
+    fn canonical_tag(&self, code: &str) -> Option<LanguageTag> {
+        let language_tag = |code: &str| match grammar::parse_language_tag(code).ok()? {
+            Tag::Language(tag) => Some(tag.canonical_case()),
+            Tag::Grandfathered(_) => None,
+        };
+        let tag = match grammar::parse_language_tag(code).ok()? {
+            Tag::Grandfathered(tag) => language_tag(
+                self.db
+                    .db_lookup(format!("grandfathered-{}", tag).as_str().into())
+                    .concept?
+                    .registry_value("Preferred-Value")?,
+            )?,
+            Tag::Language(tag) => tag.canonical_case(),
+        };
+        let preferred = self
+            .lookup_redundant(&tag, false)
+            .and_then(|r| r.registry_value("Preferred-Value").map(str::to_string));
+        let tag = match preferred {
+            Some(preferred) => language_tag(&preferred)?,
+            None => tag,
+        };
+        let records = tag.lookup_records(&self.db, REGISTRY_FIELDS, false);
+        Some(tag.canonicalize(&records).0)
     }
 }
 
@@ -355,22 +1326,33 @@ where
 mod tests {
     use super::*;
     fn create_concept(code: &str, property_type: &str, display: Option<&str>) -> Concept {
-        let mut properties = vec![Property {
-            code: "type".to_string(),
-            value: ValueX::ValueString(property_type.to_string()),
-        }];
-        if let Some(display) = display {
-            properties.push(Property {
-                code: "display".to_string(),
-                value: ValueX::ValueString(display.to_string()),
-            });
-        }
         Concept {
-            code: code.to_string(),
-            properties,
-            ..Default::default()
+            code: format!("{}-{}", property_type, code),
+            display: display.map(|d| d.to_string()),
+            properties: vec![],
+            designations: Vec::new(),
         }
     }
+    fn create_record(
+        code: &str,
+        record_type: &str,
+        display: &str,
+        fields: Vec<(&str, &str)>,
+    ) -> Concept {
+        let mut concept = create_concept(code, record_type, Some(display));
+        concept.properties = fields
+            .into_iter()
+            .map(|(field, value)| Property {
+                code: field.to_string(),
+                value: match field {
+                    "Added" | "Deprecated" => ValueX::ValueDateTime(value.to_string()),
+                    _ => ValueX::ValueString(value.to_string()),
+                },
+            })
+            .collect();
+        concept
+    }
+
     fn assert_parse_result(result: ParseResponse, expected: ParseResponse) {
         println!("{:#?}", result);
         assert_eq!(result.concept, expected.concept);
@@ -382,24 +1364,34 @@ mod tests {
         }
     }
 
+    fn string(value: &str) -> ValueX {
+        ValueX::ValueString(value.to_string())
+    }
+
+    fn coding(code: &str, display: Option<&str>) -> ValueX {
+        registry_coding(code.to_string(), display.map(str::to_string))
+    }
+
     fn create_expected_result(
         code: &str,
-        property_types: Vec<(&str, &str)>,
+        display: &str,
+        property_types: Vec<(&str, ValueX)>,
         details: Vec<ParseDetail>,
     ) -> ParseResponse {
         let mut properties = Vec::new();
         for (pn, pv) in property_types {
             properties.push(Property {
                 code: pn.to_string(),
-                value: ValueX::ValueString(pv.to_string()),
+                value: pv,
             });
         }
         ParseResponse {
             details,
             concept: Some(Concept {
                 code: code.to_string(),
+                display: Some(display.to_string()),
                 properties,
-                ..Default::default()
+                designations: Vec::new(),
             }),
         }
     }
@@ -414,8 +1406,23 @@ mod tests {
         let result = parser.parse(ParseRequest {
             code: code.clone(),
             properties: None,
+            ..Default::default()
         });
-        let expected = create_expected_result(&code, vec![("language", "en")], vec![]);
+        let expected = create_expected_result(
+            &code,
+            "English",
+            vec![
+                ("language", coding("language-en", Some("English"))),
+                ("canonicalCase", string(code.as_str())),
+                ("canonical", string(code.as_str())),
+                ("maximized", string("en-Latn-US")),
+                ("minimized", string("en")),
+                ("collection", coding("language-gmw", None)),
+                ("collection", coding("language-gem", None)),
+                ("collection", coding("language-ine", None)),
+            ],
+            vec![],
+        );
         assert_parse_result(result, expected);
     }
 
@@ -430,9 +1437,23 @@ mod tests {
         let result = parser.parse(ParseRequest {
             code: code.clone(),
             properties: None,
+            ..Default::default()
         });
-        let expected =
-            create_expected_result(&code, vec![("language", "zh"), ("script", "Hant")], vec![]);
+        let expected = create_expected_result(
+            &code,
+            "Chinese (Traditional)",
+            vec![
+                ("language", coding("language-zh", Some("Chinese"))),
+                ("script", coding("script-Hant", Some("Traditional"))),
+                ("canonicalCase", string(code.as_str())),
+                ("canonical", string(code.as_str())),
+                ("maximized", string("zh-Hant-TW")),
+                ("minimized", string("zh-TW")),
+                ("collection", coding("language-zhx", None)),
+                ("collection", coding("language-sit", None)),
+            ],
+            vec![],
+        );
         assert_parse_result(result, expected);
     }
 
@@ -447,9 +1468,72 @@ mod tests {
         let result = parser.parse(ParseRequest {
             code: code.clone(),
             properties: None,
+            ..Default::default()
+        });
+        let expected = create_expected_result(
+            &code,
+            "English (United States)",
+            vec![
+                ("language", coding("language-en", Some("English"))),
+                ("region", coding("region-US", Some("United States"))),
+                ("canonicalCase", string(code.as_str())),
+                ("canonical", string(code.as_str())),
+                ("maximized", string("en-Latn-US")),
+                ("minimized", string("en")),
+                ("collection", coding("language-gmw", None)),
+                ("collection", coding("language-gem", None)),
+                ("collection", coding("language-ine", None)),
+                ("regionContainment", coding("region-021", None)),
+                ("regionContainment", coding("region-003", None)),
+                ("regionContainment", coding("region-019", None)),
+                ("regionContainment", coding("region-001", None)),
+            ],
+            vec![],
+        );
+        assert_parse_result(result, expected);
+    }
+
+    #[test]
+    fn test_parse_is_case_insensitive() {
+        let mut db = mock_terminology_db::MockTerminologyDb::new();
+        db.insert(create_concept("en", "language", Some("English")));
+        db.insert(create_concept("US", "region", Some("United States")));
+        let parser = Guest::new(db);
+
+        let code = "EN-us-X-Priv".to_string();
+        let result = parser.parse(ParseRequest {
+            code: code.clone(),
+            properties: None,
+            ..Default::default()
         });
-        let expected =
-            create_expected_result(&code, vec![("language", "en"), ("region", "US")], vec![]);
+        let expected = create_expected_result(
+            &code,
+            "English (United States, Private-Use: priv)",
+            vec![
+                ("language", coding("language-en", Some("English"))),
+                ("region", coding("region-US", Some("United States"))),
+                ("privateUse", string("priv")),
+                ("canonicalCase", string("en-US-x-priv")),
+                ("canonical", string("en-US-x-priv")),
+                ("maximized", string("en-Latn-US-x-priv")),
+                ("minimized", string("en-x-priv")),
+                ("collection", coding("language-gmw", None)),
+                ("collection", coding("language-gem", None)),
+                ("collection", coding("language-ine", None)),
+                ("regionContainment", coding("region-021", None)),
+                ("regionContainment", coding("region-003", None)),
+                ("regionContainment", coding("region-019", None)),
+                ("regionContainment", coding("region-001", None)),
+            ],
+            vec![ParseDetail {
+                severity: Severity::Information,
+                key: "canonicalCase".to_string(),
+                value: ValueX::ValueString(
+                    "Tag is not in canonical case; expected en-US-x-priv".to_string(),
+                ),
+                location: Location::default(),
+            }],
+        );
         assert_parse_result(result, expected);
     }
 
@@ -466,14 +1550,26 @@ mod tests {
         let result = parser.parse(ParseRequest {
             code: code.clone(),
             properties: None,
+            ..Default::default()
         });
         let expected = create_expected_result(
             &code,
+            "Slovenian (Italy, Nadiza dialect, Resian dialect)",
             vec![
-                ("language", "sl"),
-                ("region", "IT"),
-                ("variant", "nedis"),
-                ("variant", "rozaj"),
+                ("language", coding("language-sl", Some("Slovenian"))),
+                ("region", coding("region-IT", Some("Italy"))),
+                ("variant", coding("variant-nedis", Some("Nadiza dialect"))),
+                ("variant", coding("variant-rozaj", Some("Resian dialect"))),
+                ("canonicalCase", string(code.as_str())),
+                ("canonical", string(code.as_str())),
+                ("maximized", string("sl-Latn-IT-nedis-rozaj")),
+                ("minimized", string("sl-IT-nedis-rozaj")),
+                ("collection", coding("language-zls", None)),
+                ("collection", coding("language-sla", None)),
+                ("collection", coding("language-ine", None)),
+                ("regionContainment", coding("region-039", None)),
+                ("regionContainment", coding("region-150", None)),
+                ("regionContainment", coding("region-001", None)),
             ],
             vec![],
         );
@@ -491,13 +1587,27 @@ mod tests {
         let result = parser.parse(ParseRequest {
             code: code.clone(),
             properties: None,
+            ..Default::default()
         });
         let expected = create_expected_result(
             &code,
+            "English (United States, Phonebook Sort Order)",
             vec![
-                ("language", "en"),
-                ("region", "US"),
-                ("extension", "u-co-phonebk"),
+                ("language", coding("language-en", Some("English"))),
+                ("region", coding("region-US", Some("United States"))),
+                ("extension", string("u-co-phonebk")),
+                ("collation", string("phonebk")),
+                ("canonicalCase", string(code.as_str())),
+                ("canonical", string(code.as_str())),
+                ("maximized", string("en-Latn-US-u-co-phonebk")),
+                ("minimized", string("en-u-co-phonebk")),
+                ("collection", coding("language-gmw", None)),
+                ("collection", coding("language-gem", None)),
+                ("collection", coding("language-ine", None)),
+                ("regionContainment", coding("region-021", None)),
+                ("regionContainment", coding("region-003", None)),
+                ("regionContainment", coding("region-019", None)),
+                ("regionContainment", coding("region-001", None)),
             ],
             vec![],
         );
@@ -514,10 +1624,22 @@ mod tests {
         let result = parser.parse(ParseRequest {
             code: code.clone(),
             properties: None,
+            ..Default::default()
         });
         let expected = create_expected_result(
             &code,
-            vec![("language", "en"), ("privateUse", "shhabc")],
+            "English (Private-Use: shhabc)",
+            vec![
+                ("language", coding("language-en", Some("English"))),
+                ("privateUse", string("shhabc")),
+                ("canonicalCase", string(code.as_str())),
+                ("canonical", string(code.as_str())),
+                ("maximized", string("en-Latn-US-x-shhabc")),
+                ("minimized", string("en-x-shhabc")),
+                ("collection", coding("language-gmw", None)),
+                ("collection", coding("language-gem", None)),
+                ("collection", coding("language-ine", None)),
+            ],
             vec![],
         );
         assert_parse_result(result, expected);
@@ -532,14 +1654,21 @@ mod tests {
         let result = parser.parse(ParseRequest {
             code: code.clone(),
             properties: None,
+            ..Default::default()
         });
         let expected = create_expected_result(
             &code,
-            vec![("language", "invalid")],
+            "invalid",
+            vec![
+                ("language", coding("language-invalid", None)),
+                ("canonicalCase", string(code.as_str())),
+                ("canonical", string(code.as_str())),
+            ],
             vec![ParseDetail {
                 severity: Severity::Error,
                 key: "language".to_string(),
                 value: ValueX::ValueString("Invalid language code: invalid".to_string()),
+                location: Location::default(),
             }],
         );
         assert_parse_result(result, expected);
@@ -555,13 +1684,14 @@ mod tests {
         let result = parser.parse(ParseRequest {
             code: code.clone(),
             properties: None,
+            ..Default::default()
         });
         println!("{:#?}", result);
         assert!(result.concept.is_some());
         assert!(result
             .details
             .iter()
-            .any(|d| d.severity == Severity::Warning && d.key == "extLang"));
+            .any(|d| d.severity == Severity::Warning && d.key == "extlang"));
     }
 
     #[test]
@@ -575,14 +1705,28 @@ mod tests {
         let result = parser.parse(ParseRequest {
             code: code.clone(),
             properties: None,
+            ..Default::default()
         });
         let expected = create_expected_result(
             &code,
+            "English (United States, Phonebook Sort Order, Private-Use: priv)",
             vec![
-                ("language", "en"),
-                ("region", "US"),
-                ("extension", "u-co-phonebk"),
-                ("privateUse", "priv"),
+                ("language", coding("language-en", Some("English"))),
+                ("region", coding("region-US", Some("United States"))),
+                ("extension", string("u-co-phonebk")),
+                ("privateUse", string("priv")),
+                ("collation", string("phonebk")),
+                ("canonicalCase", string(code.as_str())),
+                ("canonical", string(code.as_str())),
+                ("maximized", string("en-Latn-US-u-co-phonebk-x-priv")),
+                ("minimized", string("en-u-co-phonebk-x-priv")),
+                ("collection", coding("language-gmw", None)),
+                ("collection", coding("language-gem", None)),
+                ("collection", coding("language-ine", None)),
+                ("regionContainment", coding("region-021", None)),
+                ("regionContainment", coding("region-003", None)),
+                ("regionContainment", coding("region-019", None)),
+                ("regionContainment", coding("region-001", None)),
             ],
             vec![],
         );
@@ -606,14 +1750,28 @@ mod tests {
         let result = parser.parse(ParseRequest {
             code: code.clone(),
             properties: None,
+            ..Default::default()
         });
         let expected = create_expected_result(
             &code,
+            "English (United States, Phonebook Sort Order, Private-Use: private)",
             vec![
-                ("language", "en"),
-                ("region", "US"),
-                ("extension", "u-co-phonebk"),
-                ("privateUse", "private"),
+                ("language", coding("language-en", Some("English"))),
+                ("region", coding("region-US", Some("United States"))),
+                ("extension", string("u-co-phonebk")),
+                ("privateUse", string("private")),
+                ("collation", string("phonebk")),
+                ("canonicalCase", string(code.as_str())),
+                ("canonical", string(code.as_str())),
+                ("maximized", string("en-Latn-US-u-co-phonebk-x-private")),
+                ("minimized", string("en-u-co-phonebk-x-private")),
+                ("collection", coding("language-gmw", None)),
+                ("collection", coding("language-gem", None)),
+                ("collection", coding("language-ine", None)),
+                ("regionContainment", coding("region-021", None)),
+                ("regionContainment", coding("region-003", None)),
+                ("regionContainment", coding("region-019", None)),
+                ("regionContainment", coding("region-001", None)),
             ],
             vec![],
         );
@@ -630,33 +1788,1625 @@ mod tests {
         let result = parser.parse(ParseRequest {
             code: code.clone(),
             properties: None,
+            ..Default::default()
         });
         assert!(result.concept.is_none());
     }
-    mod mock_terminology_db {
 
-        use super::*;
-        pub struct MockTerminologyDb {
-            concepts: Vec<Concept>,
-        }
+    #[test]
+    fn test_parse_grandfathered_tag() {
+        let mut db = mock_terminology_db::MockTerminologyDb::new();
+        db.insert(create_record(
+            "i-klingon",
+            "grandfathered",
+            "Klingon",
+            vec![
+                ("Added", "1999-05-26"),
+                ("Deprecated", "2004-02-24"),
+                ("Preferred-Value", "tlh"),
+            ],
+        ));
+        let parser = Guest::new(db);
 
-        impl MockTerminologyDb {
-            pub fn new() -> Self {
-                MockTerminologyDb {
-                    concepts: Vec::new(),
-                }
-            }
+        let code = "I-Klingon".to_string();
+        let result = parser.parse(ParseRequest {
+            code: code.clone(),
+            properties: None,
+            ..Default::default()
+        });
+        let expected = ParseResponse {
+            details: vec![
+                ParseDetail {
+                    severity: Severity::Information,
+                    key: "canonicalCase".to_string(),
+                    value: ValueX::ValueString(
+                        "Tag is not in canonical case; expected i-klingon".to_string(),
+                    ),
+                    location: Location::default(),
+                },
+                ParseDetail {
+                    severity: Severity::Warning,
+                    key: "deprecated".to_string(),
+                    value: ValueX::ValueString(
+                        "Tag i-klingon (grandfathered) was deprecated on 2004-02-24; use tlh"
+                            .to_string(),
+                    ),
+                    location: Location::default(),
+                },
+            ],
+            concept: Some(Concept {
+                code: code.clone(),
+                display: Some("Klingon".to_string()),
+                properties: vec![
+                    Property {
+                        code: "canonicalCase".to_string(),
+                        value: ValueX::ValueString("i-klingon".to_string()),
+                    },
+                    Property {
+                        code: "canonical".to_string(),
+                        value: ValueX::ValueString("tlh".to_string()),
+                    },
+                    Property {
+                        code: "deprecated".to_string(),
+                        value: ValueX::ValueDateTime("2004-02-24".to_string()),
+                    },
+                    Property {
+                        code: "preferredValue".to_string(),
+                        value: ValueX::ValueString("tlh".to_string()),
+                    },
+                ],
+                designations: Vec::new(),
+            }),
+        };
+        assert_parse_result(result, expected);
+    }
 
-            pub fn insert(&mut self, concept: Concept) {
-                self.concepts.push(concept);
-            }
+    #[test]
+    fn test_parse_regular_grandfathered_tag_is_not_decomposed() {
+        let mut db = mock_terminology_db::MockTerminologyDb::new();
+        db.insert(create_concept("zh", "language", Some("Chinese")));
+        db.insert(create_record(
+            "zh-min-nan",
+            "grandfathered",
+            "Min Nan Chinese",
+            vec![("Added", "2001-03-26")],
+        ));
+        let parser = Guest::new(db);
+
+        let result = parser.parse(ParseRequest {
+            code: "zh-min-nan".to_string(),
+            properties: None,
+            ..Default::default()
+        });
+        let concept = result.concept.unwrap();
+        assert_eq!(concept.display.as_deref(), Some("Min Nan Chinese"));
+        assert!(!concept.properties.iter().any(|p| p.code == "extlang"));
+        assert!(result.details.is_empty());
+    }
+
+    #[test]
+    fn test_parse_unregistered_grandfathered_tag() {
+        let parser = Guest::new(mock_terminology_db::MockTerminologyDb::new());
+
+        let result = parser.parse(ParseRequest {
+            code: "en-GB-oed".to_string(),
+            properties: None,
+            ..Default::default()
+        });
+        assert!(result.concept.is_some());
+        assert!(result
+            .details
+            .iter()
+            .any(|d| d.severity == Severity::Warning && d.key == "grandfathered"));
+    }
+
+    #[test]
+    fn test_parse_redundant_tag() {
+        let mut db = mock_terminology_db::MockTerminologyDb::new();
+        db.insert(create_concept("sgn", "language", Some("Sign languages")));
+        db.insert(create_concept("BR", "region", Some("Brazil")));
+        db.insert(create_record(
+            "sgn-BR",
+            "redundant",
+            "Brazilian Sign Language",
+            vec![
+                ("Added", "2001-11-11"),
+                ("Deprecated", "2009-07-29"),
+                ("Preferred-Value", "bzs"),
+            ],
+        ));
+        let parser = Guest::new(db);
+
+        let code = "sgn-BR".to_string();
+        let result = parser.parse(ParseRequest {
+            code: code.clone(),
+            properties: None,
+            ..Default::default()
+        });
+        let mut expected = create_expected_result(
+            &code,
+            "Brazilian Sign Language",
+            vec![
+                ("language", coding("language-sgn", Some("Sign languages"))),
+                ("region", coding("region-BR", Some("Brazil"))),
+                ("canonicalCase", string(code.as_str())),
+                ("canonical", string("bzs")),
+                ("regionContainment", coding("region-005", None)),
+                ("regionContainment", coding("region-419", None)),
+                ("regionContainment", coding("region-019", None)),
+                ("regionContainment", coding("region-001", None)),
+            ],
+            vec![ParseDetail {
+                severity: Severity::Warning,
+                key: "deprecated".to_string(),
+                value: ValueX::ValueString(
+                    "Tag sgn-BR (redundant) was deprecated on 2009-07-29; use bzs".to_string(),
+                ),
+                location: Location::default(),
+            }],
+        );
+        expected.concept.as_mut().unwrap().properties.extend([
+            Property {
+                code: "deprecated".to_string(),
+                value: ValueX::ValueDateTime("2009-07-29".to_string()),
+            },
+            Property {
+                code: "preferredValue".to_string(),
+                value: ValueX::ValueString("bzs".to_string()),
+            },
+        ]);
+        assert_parse_result(result, expected);
+    }
+
+    fn deprecated_records_db() -> mock_terminology_db::MockTerminologyDb {
+        let mut db = mock_terminology_db::MockTerminologyDb::new();
+        db.insert(create_concept("he", "language", Some("Hebrew")));
+        db.insert(create_record(
+            "iw",
+            "language",
+            "Hebrew",
+            vec![("Deprecated", "1989-01-01"), ("Preferred-Value", "he")],
+        ));
+        db.insert(create_concept("IL", "region", Some("Israel")));
+        db.insert(create_concept("MM", "region", Some("Myanmar")));
+        db.insert(create_record(
+            "BU",
+            "region",
+            "Burma",
+            vec![("Deprecated", "1989-12-05"), ("Preferred-Value", "MM")],
+        ));
+        db.insert(create_record(
+            "i-klingon",
+            "grandfathered",
+            "Klingon",
+            vec![("Deprecated", "2004-02-24"), ("Preferred-Value", "tlh")],
+        ));
+        db
+    }
+
+    #[test]
+    fn test_parse_deprecated_subtags_as_of() {
+        let mut db = deprecated_records_db();
+        db.insert(create_concept("hy", "language", Some("Armenian")));
+        db.insert(create_record(
+            "arevela",
+            "variant",
+            "Eastern Armenian",
+            vec![("Deprecated", "2018-03-24"), ("Prefix", "hy")],
+        ));
+        db.insert(create_concept("en", "language", Some("English")));
+        db.insert(create_record(
+            "SS",
+            "region",
+            "South Sudan",
+            vec![("Added", "2011-08-16")],
+        ));
+        let parser = Guest::new(db);
+        let parse = |code: &str, conformance, as_of: Option<&str>| {
+            parser.parse(ParseRequest {
+                code: code.to_string(),
+                properties: None,
+                conformance,
+                as_of: as_of.map(str::to_string),
+                ..Default::default()
+            })
+        };
+        let messages = |result: &ParseResponse| -> Vec<(String, Severity, String)> {
+            result
+                .details
+                .iter()
+                .map(|d| {
+                    (
+                        d.key.clone(),
+                        d.severity.clone(),
+                        d.value.as_str().unwrap().to_string(),
+                    )
+                })
+                .collect()
+        };
+
+        let result = parse("hy-arevela", None, None);
+        assert_eq!(
+            messages(&result),
+            vec![(
+                "variant".to_string(),
+                Severity::Warning,
+                "Deprecated variant subtag arevela (since 2018-03-24) has no replacement"
+                    .to_string()
+            )]
+        );
+        assert!(messages(&parse("hy-arevela", None, Some("2010-01-01"))).is_empty());
+
+        // Burma was renamed after the as-of date, so only iw is deprecated.
+        let result = parse("iw-BU", None, Some("1989-06-01"));
+        assert_eq!(
+            string_property(result.concept.as_ref().unwrap(), "canonical").unwrap(),
+            "he-BU"
+        );
+        let keys: Vec<String> = result.details.iter().map(|d| d.key.clone()).collect();
+        assert_eq!(keys, vec!["language"]);
+
+        assert!(parse("en-SS", None, None).details.is_empty());
+        let result = parse("en-SS", None, Some("2010-01-01T00:00:00Z"));
+        assert_eq!(
+            messages(&result),
+            vec![(
+                "region".to_string(),
+                Severity::Warning,
+                "The region subtag SS was not valid yet: it was added on 2011-08-16".to_string()
+            )]
+        );
+        assert_eq!(result.details[0].location.start, Some(3));
+        let result = parse("en-SS", Some(Conformance::Valid), Some("2010-01-01"));
+        assert_eq!(result.details[0].severity, Severity::Error);
+
+        // Dates are compared as text, so they must be YYYY-MM-DD.
+        for as_of in ["2010-1-1", "01/01/2010", "2010-13-01", "2010-01-01Z"] {
+            let result = parse("en-SS", None, Some(as_of));
+            assert!(result.concept.is_none(), "{}", as_of);
+            assert_eq!(result.details.len(), 1);
+            assert_eq!(result.details[0].key, "asOf");
+            assert_eq!(result.details[0].severity, Severity::Error);
         }
 
-        impl TerminologyDb for MockTerminologyDb {
-            fn db_lookup(&self, req: LookupRequest) -> LookupResponse {
-                LookupResponse {
-                    concept: self.concepts.iter().find(|c| c.code == req.code).cloned(),
+        // Whole-tag records are checked too.
+        let mut db = mock_terminology_db::MockTerminologyDb::new();
+        db.insert(create_concept("sgn", "language", Some("Sign languages")));
+        db.insert(create_concept("BR", "region", Some("Brazil")));
+        db.insert(create_record(
+            "sgn-BR",
+            "redundant",
+            "Brazilian Sign Language",
+            vec![("Added", "2001-11-11")],
+        ));
+        db.insert(create_record(
+            "i-klingon",
+            "grandfathered",
+            "Klingon",
+            vec![("Added", "1999-05-26")],
+        ));
+        let parser = Guest::new(db);
+        let parse = |code: &str| {
+            parser.parse(ParseRequest {
+                code: code.to_string(),
+                conformance: Some(Conformance::Valid),
+                as_of: Some("1999-01-01".to_string()),
+                ..Default::default()
+            })
+        };
+        let result = parse("sgn-BR");
+        assert_eq!(
+            messages(&result).last().unwrap(),
+            &(
+                "redundant".to_string(),
+                Severity::Error,
+                "Tag sgn-BR (redundant) was not valid yet: it was added on 2001-11-11".to_string()
+            )
+        );
+        let result = parse("i-klingon");
+        assert_eq!(
+            messages(&result),
+            vec![(
+                "grandfathered".to_string(),
+                Severity::Error,
+                "Tag i-klingon (grandfathered) was not valid yet: it was added on 1999-05-26"
+                    .to_string()
+            )]
+        );
+    }
+
+    #[test]
+    fn test_parse_private_use_and_special_subtags() {
+        let mut db = mock_terminology_db::MockTerminologyDb::new();
+        db.insert(create_concept("qaa..qtz", "language", Some("Private use")));
+        db.insert(create_concept(
+            "mul",
+            "language",
+            Some("Multiple languages"),
+        ));
+        db.insert(create_concept("Latn", "script", Some("Latin")));
+        db.insert(create_concept("en", "language", Some("English")));
+        db.insert(create_concept("XA..XZ", "region", Some("Private use")));
+        let parser = Guest::new(db);
+        let parse = |code: &str| {
+            parser.parse(ParseRequest {
+                code: code.to_string(),
+                properties: None,
+                conformance: Some(Conformance::Valid),
+                ..Default::default()
+            })
+        };
+
+        // The range record stands for every subtag in it; ranges the host
+        // doesn't carry are still valid.
+        let result = parse("qab-Qaab-QM");
+        assert!(
+            result
+                .details
+                .iter()
+                .all(|d| d.severity == Severity::Information),
+            "{:?}",
+            result.details
+        );
+        assert_eq!(result.details.len(), 3);
+        assert_eq!(
+            result.details[0].value.as_str().unwrap(),
+            "qab is a private use language subtag (range qaa..qtz): its meaning is set by private agreement"
+        );
+        let concept = result.concept.unwrap();
+        assert_eq!(
+            property_value(&concept, "language").unwrap(),
+            coding("language-qaa..qtz", Some("Private use"))
+        );
+        assert_eq!(
+            property_value(&concept, "script").unwrap(),
+            coding("script-Qaaa..Qabx", Some("Private use"))
+        );
+        assert_eq!(
+            property_value(&concept, "region").unwrap(),
+            coding("region-QM..QZ", Some("Private use"))
+        );
+
+        let result = parse("mul-Latn");
+        assert_eq!(result.details.len(), 1);
+        assert_eq!(
+            result.details[0].value.as_str().unwrap(),
+            "mul (Multiple languages): the content is in several languages"
+        );
+        assert_eq!(
+            result.concept.unwrap().display.unwrap(),
+            "Multiple languages (Latin)"
+        );
+
+        let result = parse("und-Zyyy-ZZ");
+        let keys: Vec<&str> = result.details.iter().map(|d| d.key.as_str()).collect();
+        assert_eq!(keys, vec!["language", "script", "region"]);
+        assert!(result
+            .details
+            .iter()
+            .all(|d| d.severity == Severity::Information));
+        assert_eq!(
+            result.concept.unwrap().display.unwrap(),
+            "Undetermined (Common, Unknown Region)"
+        );
+
+        // CLDR's Kosovo code wins over the host's record for its range.
+        let result = parse("en-XK");
+        assert_eq!(result.details.len(), 1);
+        assert_eq!(
+            result.details[0].value.as_str().unwrap(),
+            "XK (Kosovo): Kosovo, by the CLDR convention for this private use code"
+        );
+        let concept = result.concept.unwrap();
+        assert_eq!(
+            property_value(&concept, "region").unwrap(),
+            coding("region-XK", Some("Kosovo"))
+        );
+        assert_eq!(concept.display.unwrap(), "English (Kosovo)");
+
+        assert_eq!(parse("qua").details[0].severity, Severity::Error);
+    }
+
+    #[test]
+    fn test_parse_replaces_deprecated_subtags() {
+        let parser = Guest::new(deprecated_records_db());
+
+        let result = parser.parse(ParseRequest {
+            code: "iw-BU-u-nu-hebr-a-bbb".to_string(),
+            properties: None,
+            ..Default::default()
+        });
+        let concept = result.concept.unwrap();
+        assert!(concept.properties.contains(&Property {
+            code: "canonical".to_string(),
+            value: ValueX::ValueString("he-MM-a-bbb-u-nu-hebr".to_string()),
+        }));
+        let warnings: Vec<_> = result
+            .details
+            .iter()
+            .filter(|d| d.severity == Severity::Warning)
+            .map(|d| d.key.as_str())
+            .collect();
+        assert_eq!(warnings, vec!["language", "region"]);
+    }
+
+    #[test]
+    fn test_subsumes_compares_canonical_form() {
+        let parser = Guest::new(deprecated_records_db());
+
+        assert!(subsumes_in(&parser, "he", "iw-IL", &[]));
+        assert!(subsumes_in(&parser, "iw", "he-IL", &[]));
+        assert!(subsumes_in(&parser, "he-MM", "iw-BU", &[]));
+        assert!(subsumes_in(&parser, "tlh", "i-klingon", &[]));
+        assert!(!subsumes_in(&parser, "he-IL", "iw-BU", &[]));
+    }
+
+    fn extlang_records_db() -> mock_terminology_db::MockTerminologyDb {
+        let mut db = mock_terminology_db::MockTerminologyDb::new();
+        db.insert(create_concept("zh", "language", Some("Chinese")));
+        db.insert(create_concept("en", "language", Some("English")));
+        db.insert(create_concept("yue", "language", Some("Cantonese")));
+        db.insert(create_concept("HK", "region", Some("Hong Kong")));
+        for (extlang, display) in [("yue", "Cantonese"), ("cmn", "Mandarin Chinese")] {
+            db.insert(create_record(
+                extlang,
+                "extlang",
+                display,
+                vec![("Preferred-Value", extlang), ("Prefix", "zh")],
+            ));
+        }
+        db
+    }
+
+    fn string_property(concept: &Concept, code: &str) -> Option<String> {
+        concept
+            .properties
+            .iter()
+            .find(|p| p.code == code)
+            .map(|p| match &p.value {
+                ValueX::ValueString(s) => s.clone(),
+                other => panic!("{} is not a string: {:?}", code, other),
+            })
+    }
+
+    fn property_value(concept: &Concept, code: &str) -> Option<ValueX> {
+        concept
+            .properties
+            .iter()
+            .find(|p| p.code == code)
+            .map(|p| p.value.clone())
+    }
+
+    #[test]
+    fn test_parse_extlang_and_language_forms_are_equivalent() {
+        let parser = Guest::new(extlang_records_db());
+
+        for code in ["zh-yue-HK", "yue-HK"] {
+            let result = parser.parse(ParseRequest {
+                code: code.to_string(),
+                properties: None,
+                ..Default::default()
+            });
+            assert!(result.details.is_empty(), "{:?}", result.details);
+            let concept = result.concept.unwrap();
+            assert_eq!(string_property(&concept, "canonical").unwrap(), "yue-HK");
+            assert_eq!(
+                string_property(&concept, "extlangForm").unwrap(),
+                "zh-yue-HK"
+            );
+        }
+        assert!(
+            parser
+                .subsumes(SubsumesRequest {
+                    ancestor: "yue".to_string(),
+                    descendant: "zh-yue-HK".to_string(),
+                    modes: Vec::new(),
+                })
+                .subsumes
+        );
+    }
+
+    #[test]
+    fn test_parse_extlang_with_wrong_prefix() {
+        let parser = Guest::new(extlang_records_db());
+
+        let result = parser.parse(ParseRequest {
+            code: "en-yue".to_string(),
+            properties: None,
+            ..Default::default()
+        });
+        assert!(result
+            .details
+            .iter()
+            .any(|d| d.severity == Severity::Warning && d.key == "extlang"));
+        // The tag is not rewritten to the extlang's language.
+        let concept = result.concept.unwrap();
+        assert_eq!(string_property(&concept, "canonical").unwrap(), "en-yue");
+        assert!(string_property(&concept, "extlangForm").is_none());
+    }
+
+    #[test]
+    fn test_parse_multiple_extlangs() {
+        let parser = Guest::new(extlang_records_db());
+
+        let result = parser.parse(ParseRequest {
+            code: "zh-yue-cmn".to_string(),
+            properties: None,
+            ..Default::default()
+        });
+        assert!(result.concept.is_some());
+        assert!(result
+            .details
+            .iter()
+            .any(|d| d.severity == Severity::Error && d.key == "extlang"));
+    }
+
+    #[test]
+    fn test_parse_suppressed_script() {
+        let mut db = mock_terminology_db::MockTerminologyDb::new();
+        db.insert(create_record(
+            "en",
+            "language",
+            "English",
+            vec![("Suppress-Script", "Latn")],
+        ));
+        db.insert(create_concept("Latn", "script", Some("Latin")));
+        db.insert(create_concept("Dsrt", "script", Some("Deseret")));
+        db.insert(create_concept("US", "region", Some("United States")));
+        let parser = Guest::new(db);
+
+        let result = parser.parse(ParseRequest {
+            code: "en-Latn-US".to_string(),
+            properties: None,
+            ..Default::default()
+        });
+        let concept = result.concept.unwrap();
+        assert_eq!(string_property(&concept, "canonical").unwrap(), "en-US");
+        assert_eq!(result.details.len(), 1);
+        assert_eq!(result.details[0].severity, Severity::Warning);
+        assert_eq!(result.details[0].key, "script");
+
+        let result = parser.parse(ParseRequest {
+            code: "en-Dsrt-US".to_string(),
+            properties: None,
+            ..Default::default()
+        });
+        let concept = result.concept.unwrap();
+        assert_eq!(
+            string_property(&concept, "canonical").unwrap(),
+            "en-Dsrt-US"
+        );
+        assert!(result.details.is_empty());
+
+        assert!(subsumes_in(&parser, "en-US", "en-Latn-US", &[]));
+        assert!(subsumes_in(&parser, "en-Latn-US", "en-US", &[]));
+    }
+
+    #[test]
+    fn test_parse_variant_prefixes() {
+        let mut db = mock_terminology_db::MockTerminologyDb::new();
+        db.insert(create_concept("sl", "language", Some("Slovenian")));
+        db.insert(create_concept("en", "language", Some("English")));
+        db.insert(create_concept("IT", "region", Some("Italy")));
+        db.insert(create_record(
+            "rozaj",
+            "variant",
+            "Resian",
+            vec![("Prefix", "sl")],
+        ));
+        db.insert(create_record(
+            "biske",
+            "variant",
+            "The San Giorgio dialect of Resian",
+            vec![("Prefix", "sl-rozaj")],
+        ));
+        let parser = Guest::new(db);
+        let variant_warnings = |code: &str| -> Vec<String> {
+            parser
+                .parse(ParseRequest {
+                    code: code.to_string(),
+                    properties: None,
+                    ..Default::default()
+                })
+                .details
+                .into_iter()
+                .filter(|d| d.key == "variant")
+                .map(|d| match d.value {
+                    ValueX::ValueString(s) => s,
+                    other => panic!("unexpected detail value {:?}", other),
+                })
+                .collect()
+        };
+
+        assert!(variant_warnings("sl-rozaj").is_empty());
+        assert!(variant_warnings("sl-rozaj-biske").is_empty());
+        assert!(variant_warnings("sl-IT-rozaj-biske").is_empty());
+        assert_eq!(
+            variant_warnings("sl-biske"),
+            vec!["Variant subtag biske requires one of the prefixes: sl-rozaj"]
+        );
+        assert_eq!(
+            variant_warnings("en-rozaj"),
+            vec!["Variant subtag rozaj requires one of the prefixes: sl"]
+        );
+    }
+
+    #[test]
+    fn test_parse_duplicate_variants_and_singletons() {
+        let mut db = mock_terminology_db::MockTerminologyDb::new();
+        db.insert(create_concept("de", "language", Some("German")));
+        db.insert(create_concept("DE", "region", Some("Germany")));
+        db.insert(create_concept(
+            "1901",
+            "variant",
+            Some("Traditional German orthography"),
+        ));
+        db.insert(create_concept("en", "language", Some("English")));
+        let parser = Guest::new(db);
+        let errors = |code: &str| -> Vec<String> {
+            let result = parser.parse(ParseRequest {
+                code: code.to_string(),
+                properties: None,
+                ..Default::default()
+            });
+            assert!(result.concept.is_some());
+            result
+                .details
+                .into_iter()
+                .filter(|d| d.severity == Severity::Error)
+                .map(|d| d.key)
+                .collect()
+        };
+
+        assert!(errors("de-DE-1901").is_empty());
+        assert_eq!(errors("de-DE-1901-1901"), vec!["variant"]);
+        assert_eq!(errors("DE-de-1901-1901"), vec!["variant"]);
+        assert!(errors("en-a-bbb-b-ccc").is_empty());
+        assert_eq!(errors("en-a-bbb-a-ccc"), vec!["extension"]);
+    }
+
+    #[test]
+    fn test_parse_many_variants() {
+        let parser = Guest::new(mock_terminology_db::MockTerminologyDb::new());
+
+        let result = parser.parse(ParseRequest {
+            code: "sl-rozaj-biske-1994-fonipa-fonupa-alalc97".to_string(),
+            properties: None,
+            ..Default::default()
+        });
+        assert!(result.concept.is_some());
+        assert!(parser
+            .parse(ParseRequest {
+                code: "de-1ab!".to_string(),
+                properties: None,
+                ..Default::default()
+            })
+            .concept
+            .is_none());
+    }
+
+    #[test]
+    fn test_parse_private_use_tag() {
+        let parser = Guest::new(mock_terminology_db::MockTerminologyDb::new());
+        let code = "x-whatever".to_string();
+        let result = parser.parse(ParseRequest {
+            code: code.clone(),
+            properties: None,
+            ..Default::default()
+        });
+        let expected = create_expected_result(
+            &code,
+            "x-whatever",
+            vec![
+                ("privateUse", string("whatever")),
+                ("canonicalCase", string(code.as_str())),
+                ("canonical", string(code.as_str())),
+            ],
+            vec![],
+        );
+        assert_parse_result(result, expected);
+    }
+
+    #[test]
+    fn test_parse_reports_where_the_grammar_failed() {
+        let parser = Guest::new(mock_terminology_db::MockTerminologyDb::new());
+        let result = parser.parse(ParseRequest {
+            code: "en-US-Latn".to_string(),
+            properties: None,
+            ..Default::default()
+        });
+        assert!(result.concept.is_none());
+        assert_eq!(
+            result.details[0].value,
+            ValueX::ValueString(
+                "Invalid subtag Latn at offset 6: expected a variant subtag of 5-8 letters or digits, or a digit followed by 3 letters or digits, an extension singleton or the private use singleton x".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn test_parse_details_locate_subtags() {
+        let mut db = mock_terminology_db::MockTerminologyDb::new();
+        db.insert(create_concept("en", "language", Some("English")));
+        db.insert(create_concept("Latn", "script", Some("Latin")));
+        let parser = Guest::new(db);
+
+        let result = parser.parse(ParseRequest {
+            code: "en-Latn-ZZ-u-ca-foo".to_string(),
+            properties: None,
+            ..Default::default()
+        });
+        let locations: Vec<_> = result
+            .details
+            .iter()
+            .map(|d| (d.key.as_str(), d.location.clone()))
+            .collect();
+        assert_eq!(
+            locations,
+            vec![
+                (
+                    "region",
+                    Location {
+                        start: Some(8),
+                        end: Some(10),
+                        subtag_index: Some(2),
+                    }
+                ),
+                (
+                    "calendar",
+                    Location {
+                        start: Some(16),
+                        end: Some(19),
+                        subtag_index: Some(5),
+                    }
+                ),
+            ]
+        );
+
+        let result = parser.parse(ParseRequest {
+            code: "en-x-a-b".to_string(),
+            properties: None,
+            ..Default::default()
+        });
+        assert!(result.details.is_empty());
+        let result = parser.parse(ParseRequest {
+            code: "xx-x-a".to_string(),
+            properties: None,
+            ..Default::default()
+        });
+        assert_eq!(result.details[0].location.subtag_index, Some(0));
+        assert_eq!(result.details[0].location.end, Some(2));
+    }
+
+    #[test]
+    fn test_parse_conformance_levels() {
+        let mut db = deprecated_records_db();
+        db.insert(create_concept("sl", "language", Some("Slovenian")));
+        db.insert(create_concept("en", "language", Some("English")));
+        db.insert(create_record(
+            "rozaj",
+            "variant",
+            "Resian",
+            vec![("Prefix", "sl")],
+        ));
+        let parser = Guest::new(db);
+        let parse = |code: &str, conformance| {
+            parser.parse(ParseRequest {
+                code: code.to_string(),
+                properties: None,
+                conformance,
+                ..Default::default()
+            })
+        };
+        let errors = |code: &str, conformance| -> Vec<String> {
+            parse(code, Some(conformance))
+                .details
+                .into_iter()
+                .filter(|d| d.severity == Severity::Error)
+                .map(|d| d.key)
+                .collect()
+        };
+
+        // Well-formed: syntax only, so unregistered subtags pass and there is
+        // no canonical form.
+        let result = parse("qq-ZZ-abcde-u-zz-abc", Some(Conformance::WellFormed));
+        assert!(result.details.is_empty(), "{:?}", result.details);
+        let concept = result.concept.unwrap();
+        assert_eq!(
+            property_value(&concept, "region").unwrap(),
+            coding("region-ZZ", None)
+        );
+        assert!(string_property(&concept, "canonical").is_none());
+
+        // Without a level the same problems are warnings; valid makes them
+        // errors.
+        assert!(errors("en-QL", Conformance::WellFormed).is_empty());
+        assert!(parse("en-QL", None)
+            .details
+            .iter()
+            .all(|d| d.severity == Severity::Warning));
+        assert_eq!(errors("en-QL", Conformance::Valid), vec!["region"]);
+        assert_eq!(errors("en-rozaj", Conformance::Valid), vec!["variant"]);
+        assert!(errors("sl-rozaj", Conformance::Valid).is_empty());
+        assert!(errors("iw-BU", Conformance::Valid).is_empty());
+
+        // Canonical: deprecated subtags and casing are errors too.
+        assert_eq!(errors("iw-BU", Conformance::Canonical), vec!["canonical"]);
+        assert_eq!(errors("he-il", Conformance::Canonical), vec!["canonical"]);
+        assert_eq!(
+            errors("i-klingon", Conformance::Canonical),
+            vec!["canonical"]
+        );
+        assert!(errors("he-IL", Conformance::Canonical).is_empty());
+    }
+
+    #[test]
+    fn test_macrolanguages() {
+        let mut db = mock_terminology_db::MockTerminologyDb::new();
+        db.insert(create_record(
+            "zh",
+            "language",
+            "Chinese",
+            vec![("Scope", "macrolanguage")],
+        ));
+        db.insert(create_record(
+            "cmn",
+            "language",
+            "Mandarin Chinese",
+            vec![("Macrolanguage", "zh")],
+        ));
+        db.insert(create_record(
+            "yue",
+            "language",
+            "Yue Chinese",
+            vec![("Macrolanguage", "zh")],
+        ));
+        db.insert(create_record(
+            "yue",
+            "extlang",
+            "Yue Chinese",
+            vec![("Preferred-Value", "yue"), ("Prefix", "zh")],
+        ));
+        db.insert(create_concept("CN", "region", Some("China")));
+        db.insert(create_concept("en", "language", Some("English")));
+        let parser = Guest::new(db);
+
+        let result = parser.parse(ParseRequest {
+            code: "cmn-CN".to_string(),
+            properties: None,
+            ..Default::default()
+        });
+        let concept = result.concept.unwrap();
+        assert_eq!(
+            property_value(&concept, "macrolanguage").unwrap(),
+            coding("language-zh", Some("Chinese"))
+        );
+        let result = parser.parse(ParseRequest {
+            code: "zh-yue-HK".to_string(),
+            properties: None,
+            ..Default::default()
+        });
+        assert_eq!(
+            property_value(&result.concept.unwrap(), "macrolanguage").unwrap(),
+            coding("language-zh", Some("Chinese"))
+        );
+        let result = parser.parse(ParseRequest {
+            code: "zh".to_string(),
+            properties: None,
+            ..Default::default()
+        });
+        assert!(property_value(&result.concept.unwrap(), "macrolanguage").is_none());
+
+        assert!(!subsumes_in(&parser, "zh", "cmn-CN", &[]));
+        assert!(subsumes_in(
+            &parser,
+            "zh",
+            "cmn-CN",
+            &[SubsumesMode::Macrolanguage]
+        ));
+        assert!(subsumes_in(
+            &parser,
+            "zh-CN",
+            "cmn-CN",
+            &[SubsumesMode::Macrolanguage]
+        ));
+        assert!(subsumes_in(
+            &parser,
+            "zh",
+            "zh-yue",
+            &[SubsumesMode::Macrolanguage]
+        ));
+        assert!(!subsumes_in(
+            &parser,
+            "zh-TW",
+            "cmn-CN",
+            &[SubsumesMode::Macrolanguage]
+        ));
+        assert!(!subsumes_in(
+            &parser,
+            "cmn",
+            "zh",
+            &[SubsumesMode::Macrolanguage]
+        ));
+        assert!(!subsumes_in(
+            &parser,
+            "en",
+            "cmn",
+            &[SubsumesMode::Macrolanguage]
+        ));
+
+        // The extlang's record still gives the macrolanguage when the
+        // language subtag has none.
+        let mut db = mock_terminology_db::MockTerminologyDb::new();
+        db.insert(create_record(
+            "yue",
+            "language",
+            "Yue Chinese",
+            vec![("Macrolanguage", "zh")],
+        ));
+        db.insert(create_record(
+            "yue",
+            "extlang",
+            "Yue Chinese",
+            vec![("Preferred-Value", "yue"), ("Prefix", "zh")],
+        ));
+        let result = Guest::new(db).parse(ParseRequest {
+            code: "zh-yue".to_string(),
+            ..Default::default()
+        });
+        assert_eq!(
+            property_value(&result.concept.unwrap(), "macrolanguage").unwrap(),
+            coding("language-zh", None)
+        );
+    }
+
+    #[test]
+    fn test_parse_batch() {
+        let mut db = mock_terminology_db::MockTerminologyDb::new();
+        db.insert(create_concept("en", "language", Some("English")));
+        db.insert(create_concept("es", "language", Some("Spanish")));
+        db.insert(create_concept("US", "region", Some("United States")));
+        db.insert(create_concept("GB", "region", Some("United Kingdom")));
+        let parser = Guest::new(db);
+        let request = |code: &str| ParseRequest {
+            code: code.to_string(),
+            properties: None,
+            ..Default::default()
+        };
+        let codes = ["en-US", "en-GB", "es-US", "en-US", "e", "i-klingon"];
+
+        let expected: Vec<ParseResponse> = codes.iter().map(|c| parser.parse(request(c))).collect();
+        parser.db.requests.borrow_mut().clear();
+        let response = parser.parse_batch(ParseBatchRequest {
+            requests: codes.iter().map(|c| request(c)).collect(),
+        });
+        assert_eq!(response.responses, expected);
+
+        // Each distinct lookup reaches the host once.
+        let requests = parser.db.requests.borrow();
+        let mut distinct = requests.clone();
+        distinct.sort();
+        distinct.dedup();
+        assert_eq!(requests.len(), distinct.len());
+        let language_en = requests.iter().filter(|r| r.code == "language-en").count();
+        assert_eq!(language_en, 1);
+    }
+
+    #[test]
+    fn test_collections() {
+        let mut db = mock_terminology_db::MockTerminologyDb::new();
+        db.insert(create_concept("pl", "language", Some("Polish")));
+        let parser = Guest::new(db);
+        let concept = parser
+            .parse(ParseRequest {
+                code: "pl".to_string(),
+                properties: Some(vec!["collection".to_string()]),
+                ..Default::default()
+            })
+            .concept
+            .unwrap();
+        let collections: Vec<ValueX> = concept.properties.into_iter().map(|p| p.value).collect();
+        assert_eq!(
+            collections,
+            vec![
+                coding("language-zlw", None),
+                coding("language-sla", None),
+                coding("language-ine", None),
+            ]
+        );
+
+        assert!(!subsumes_in(&parser, "sla", "pl", &[]));
+        assert!(subsumes_in(
+            &parser,
+            "sla",
+            "pl",
+            &[SubsumesMode::Collection]
+        ));
+        assert!(subsumes_in(
+            &parser,
+            "sla",
+            "pl-PL",
+            &[SubsumesMode::Collection]
+        ));
+        assert!(subsumes_in(
+            &parser,
+            "sla",
+            "zlw",
+            &[SubsumesMode::Collection]
+        ));
+        assert!(subsumes_in(
+            &parser,
+            "ine",
+            "cs",
+            &[SubsumesMode::Collection]
+        ));
+        assert!(!subsumes_in(
+            &parser,
+            "sla-CZ",
+            "pl-PL",
+            &[SubsumesMode::Collection]
+        ));
+        assert!(!subsumes_in(
+            &parser,
+            "sla",
+            "de",
+            &[SubsumesMode::Collection]
+        ));
+        assert!(!subsumes_in(
+            &parser,
+            "pl",
+            "sla",
+            &[SubsumesMode::Collection]
+        ));
+
+        // Membership outside the table is reported rather than silently
+        // missing.
+        let mut db = mock_terminology_db::MockTerminologyDb::new();
+        db.insert(create_concept("eu", "language", Some("Basque")));
+        db.insert(create_record(
+            "art",
+            "language",
+            "Artificial languages",
+            vec![("Scope", "collection")],
+        ));
+        let parser = Guest::new(db);
+        let details = |code: &str, properties: Option<Vec<String>>| -> Vec<String> {
+            parser
+                .parse(ParseRequest {
+                    code: code.to_string(),
+                    properties,
+                    ..Default::default()
+                })
+                .details
+                .iter()
+                .filter(|d| d.key == "collection" && d.severity == Severity::Information)
+                .map(|d| d.value.as_str().unwrap().to_string())
+                .collect()
+        };
+        let collection = || Some(vec!["collection".to_string()]);
+        assert_eq!(
+            details("art", collection()),
+            vec!["The languages in collection art are not known"]
+        );
+        assert_eq!(
+            details("eu", collection()),
+            vec!["Collection membership of eu is not known"]
+        );
+        // Only when the collection property is asked for by name.
+        assert!(details("eu", None).is_empty());
+    }
+
+    #[test]
+    fn test_likely_subtags() {
+        let parser = Guest::new(mock_terminology_db::MockTerminologyDb::new());
+        let concept = parser
+            .parse(ParseRequest {
+                code: "zh-TW".to_string(),
+                properties: None,
+                ..Default::default()
+            })
+            .concept
+            .unwrap();
+        assert_eq!(
+            string_property(&concept, "maximized").unwrap(),
+            "zh-Hant-TW"
+        );
+        assert_eq!(string_property(&concept, "minimized").unwrap(), "zh-TW");
+        let concept = parser
+            .parse(ParseRequest {
+                code: "en-Latn-US".to_string(),
+                properties: None,
+                conformance: Some(Conformance::WellFormed),
+                ..Default::default()
+            })
+            .concept
+            .unwrap();
+        assert!(string_property(&concept, "minimized").is_none());
+
+        assert!(!subsumes_in(&parser, "zh-Hant-TW", "zh-TW", &[]));
+        assert!(subsumes_in(
+            &parser,
+            "zh-Hant-TW",
+            "zh-TW",
+            &[SubsumesMode::LikelySubtags]
+        ));
+        assert!(subsumes_in(
+            &parser,
+            "zh-TW",
+            "zh-Hant-TW",
+            &[SubsumesMode::LikelySubtags]
+        ));
+        assert!(subsumes_in(
+            &parser,
+            "en-US",
+            "en",
+            &[SubsumesMode::LikelySubtags]
+        ));
+        assert!(!subsumes_in(
+            &parser,
+            "en-GB",
+            "en",
+            &[SubsumesMode::LikelySubtags]
+        ));
+        assert!(!subsumes_in(
+            &parser,
+            "zh-Hans",
+            "zh-TW",
+            &[SubsumesMode::LikelySubtags]
+        ));
+        // The ancestor gets its likely subtags too.
+        assert!(subsumes_in(
+            &parser,
+            "und-Hant",
+            "zh-TW",
+            &[SubsumesMode::LikelySubtags]
+        ));
+
+        let mut db = mock_terminology_db::MockTerminologyDb::new();
+        db.insert(create_concept("tlh", "language", Some("Klingon")));
+        let parser = Guest::new(db);
+        let parse = |properties: Option<Vec<String>>| {
+            parser.parse(ParseRequest {
+                code: "tlh".to_string(),
+                properties,
+                ..Default::default()
+            })
+        };
+        // The gap is only noted when likely subtags were asked for.
+        let result = parse(None);
+        assert!(string_property(result.concept.as_ref().unwrap(), "maximized").is_none());
+        assert!(!result.details.iter().any(|d| d.key == "maximized"));
+        let result = parse(Some(vec!["maximized".to_string()]));
+        let detail = result
+            .details
+            .iter()
+            .find(|d| d.key == "maximized")
+            .unwrap();
+        assert_eq!(detail.severity, Severity::Information);
+        assert_eq!(
+            detail.value.as_str().unwrap(),
+            "CLDR has no likely subtags for tlh"
+        );
+    }
+
+    #[test]
+    fn test_parse_display_language() {
+        let designated = |mut concept: Concept, names: &[(&str, &str)]| {
+            concept.designations = names
+                .iter()
+                .map(|(language, value)| Designation {
+                    language: Some(language.to_string()),
+                    value: value.to_string(),
+                })
+                .collect();
+            concept
+        };
+        let mut db = mock_terminology_db::MockTerminologyDb::new();
+        db.insert(designated(
+            create_concept("de", "language", Some("German")),
+            &[("de", "Deutsch"), ("fr", "allemand"), ("ja", "ドイツ語")],
+        ));
+        db.insert(designated(
+            create_concept("CH", "region", Some("Switzerland")),
+            &[("de", "Schweiz"), ("ja", "スイス")],
+        ));
+        db.insert(designated(
+            create_concept("zh", "language", Some("Chinese")),
+            &[("es", "chino mandarín")],
+        ));
+        db.insert(designated(
+            create_concept("Hant", "script", Some("Han (Traditional variant)")),
+            &[("es", "tradicional")],
+        ));
+        db.insert(create_concept("BR", "region", Some("Brazil")));
+        let parser = Guest::new(db);
+        let display = |code: &str, display_language: Option<&str>| {
+            parser
+                .parse(ParseRequest {
+                    code: code.to_string(),
+                    properties: None,
+                    display_language: display_language.map(str::to_string),
+                    ..Default::default()
+                })
+                .concept
+                .unwrap()
+                .display
+                .unwrap()
+        };
+        assert_eq!(display("de-CH", Some("de")), "Deutsch (Schweiz)");
+        assert!(parser.db.requests.borrow().iter().all(|r| r.designations));
+        // Designations are only asked for with a display language.
+        parser.db.requests.borrow_mut().clear();
+        assert_eq!(display("de-CH", None), "German (Switzerland)");
+        assert!(parser.db.requests.borrow().iter().all(|r| !r.designations));
+        // A regional display language takes its primary language's names.
+        assert_eq!(
+            display("zh-Hant", Some("es-MX")),
+            "chino mandarín (tradicional)"
+        );
+        // Names missing in the requested language take the CLDR English
+        // name, then the registry description.
+        assert_eq!(display("de-BR", Some("fr")), "allemand (Brazil)");
+        assert_eq!(display("zh-Hant", None), "Chinese (Traditional)");
+        // Keywords and private use only have English names; the language and
+        // region around them stay localized.
+        assert_eq!(
+            display("de-CH-u-nu-latn-x-phonebk", Some("ja")),
+            "ドイツ語（スイス、Western Digits、Private-Use：phonebk）"
+        );
+        assert_eq!(
+            display("de-CH-u-ca-buddhist", Some("de")),
+            "Deutsch (Schweiz, Buddhist Calendar)"
+        );
+        assert_eq!(
+            display("zh-Hant-x-foo", Some("es")),
+            "chino mandarín (tradicional, Private-Use: foo)"
+        );
+        assert_eq!(
+            display("zh-Hant-x-foo", None),
+            "Chinese (Traditional, Private-Use: foo)"
+        );
+    }
+
+    #[test]
+    fn test_parse_display_style() {
+        let mut db = mock_terminology_db::MockTerminologyDb::new();
+        db.insert(create_concept("en", "language", Some("English")));
+        db.insert(create_concept("US", "region", Some("United States")));
+        db.insert(create_concept("yue", "language", Some("Yue Chinese")));
+        db.insert(create_concept("HK", "region", Some("Hong Kong")));
+        let parser = Guest::new(db);
+        let display = |code: &str, display_style| {
+            parser
+                .parse(ParseRequest {
+                    code: code.to_string(),
+                    properties: None,
+                    display_style,
+                    ..Default::default()
+                })
+                .concept
+                .unwrap()
+                .display
+                .unwrap()
+        };
+        assert_eq!(display("en-US", None), "English (United States)");
+        assert_eq!(
+            display("en-US", Some(DisplayStyle::Long)),
+            "English (United States)"
+        );
+        assert_eq!(display("en-US", Some(DisplayStyle::Short)), "English (US)");
+        assert_eq!(display("yue-HK", None), "Cantonese (Hong Kong SAR China)");
+        assert_eq!(
+            display("yue-HK", Some(DisplayStyle::Short)),
+            "Cantonese (Hong Kong)"
+        );
+        // The extlang names the language, as CLDR names `zh-yue` as `yue`.
+        assert_eq!(display("zh-yue-HK", None), display("yue-HK", None));
+        assert_eq!(
+            display("zh-yue-HK", Some(DisplayStyle::Menu)),
+            "Chinese, Cantonese (Hong Kong SAR China)"
+        );
+        assert_eq!(
+            display("en-u-ca-gregory-cu-eur", None),
+            "English (Gregorian Calendar, Currency: eur)"
+        );
+    }
+
+    #[test]
+    fn test_parse_requested_properties() {
+        let parser = Guest::new(extlang_records_db());
+        let parse = |properties: Option<Vec<&str>>| {
+            parser.db.requests.borrow_mut().clear();
+            parser.parse(ParseRequest {
+                code: "yue-HK".to_string(),
+                properties: properties.map(|p| p.into_iter().map(str::to_string).collect()),
+                ..Default::default()
+            })
+        };
+        let lookups = || -> Vec<String> {
+            parser
+                .db
+                .requests
+                .borrow()
+                .iter()
+                .map(|r| r.code.clone())
+                .collect()
+        };
+
+        let result = parse(None);
+        assert!(string_property(&result.concept.unwrap(), "extlangForm").is_some());
+        assert!(lookups().contains(&"extlang-yue".to_string()));
+
+        let result = parse(Some(vec!["canonical", "region"]));
+        let concept = result.concept.unwrap();
+        let codes: Vec<&str> = concept.properties.iter().map(|p| p.code.as_str()).collect();
+        assert_eq!(codes, vec!["region", "canonical"]);
+        assert_eq!(concept.display.unwrap(), "Cantonese (Hong Kong SAR China)");
+        assert!(!lookups().contains(&"extlang-yue".to_string()));
+        let requests = parser.db.requests.borrow();
+        let language = requests.iter().find(|r| r.code == "language-yue").unwrap();
+        let fields = language.properties.as_ref().unwrap();
+        assert!(fields.contains(&"Preferred-Value".to_string()));
+        assert!(!fields.contains(&"Macrolanguage".to_string()));
+    }
+
+    #[test]
+    fn test_parse_skips_unrequested_lookups() {
+        let mut db = mock_terminology_db::MockTerminologyDb::new();
+        db.insert(create_concept("ru", "language", Some("Russian")));
+        db.insert(create_concept("ja", "language", Some("Japanese")));
+        db.insert(create_concept("JP", "region", Some("Japan")));
+        let parser = Guest::new(db);
+        let lookups = |properties: &[&str], conformance: Option<Conformance>| {
+            parser.db.requests.borrow_mut().clear();
+            parser.parse(ParseRequest {
+                code: "ru-t-ja-jp".to_string(),
+                properties: Some(properties.iter().map(|p| p.to_string()).collect()),
+                conformance,
+                ..Default::default()
+            });
+            let requests = parser.db.requests.borrow();
+            requests.iter().map(|r| r.code.clone()).collect::<Vec<_>>()
+        };
+
+        assert_eq!(lookups(&["canonical"], None), vec!["language-ru"]);
+        assert_eq!(
+            lookups(&["transformedSourceLanguage"], None),
+            vec!["language-ru", "language-ja"]
+        );
+        // Validity needs every source subtag looked up.
+        assert_eq!(
+            lookups(&["canonical"], Some(Conformance::Valid)),
+            vec!["language-ru", "language-ja", "region-JP"]
+        );
+
+        // The source records are only read for their code and display, and
+        // Added only with an as-of date.
+        let requests = parser.db.requests.borrow();
+        assert_eq!(requests[1].properties, Some(vec![]));
+        let fields = requests[0].properties.as_ref().unwrap();
+        assert!(!fields.contains(&"Added".to_string()));
+    }
+
+    #[test]
+    fn test_filter_and_lookup() {
+        let parser = Guest::new(mock_terminology_db::MockTerminologyDb::new());
+        let tags: Vec<String> = [
+            "de-DE",
+            "de-Latn-DE",
+            "de-CH",
+            "i-klingon",
+            "en--US",
+            "zh-Hant",
+        ]
+        .iter()
+        .map(|t| t.to_string())
+        .collect();
+
+        let response = parser.filter(FilterRequest {
+            ranges: vec!["de-de".to_string()],
+            tags: tags.clone(),
+            extended: false,
+        });
+        assert_eq!(response.tags, vec!["de-DE"]);
+        assert_eq!(response.details.len(), 2);
+
+        let response = parser.filter(FilterRequest {
+            ranges: vec!["de-*-DE".to_string()],
+            tags: tags.clone(),
+            extended: true,
+        });
+        assert_eq!(response.tags, vec!["de-DE", "de-Latn-DE"]);
+
+        let lookup = |ranges: &[&str], default: Option<&str>| {
+            parser
+                .lookup(RangeLookupRequest {
+                    ranges: ranges.iter().map(|r| r.to_string()).collect(),
+                    tags: tags.clone(),
+                    default: default.map(str::to_string),
+                })
+                .tag
+        };
+        assert_eq!(
+            lookup(&["zh-Hant-CN-x-private1"], None).as_deref(),
+            Some("zh-Hant")
+        );
+        assert_eq!(
+            lookup(&["fr", "de-CH-1996"], None).as_deref(),
+            Some("de-CH")
+        );
+        assert_eq!(lookup(&["fr"], Some("en")).as_deref(), Some("en"));
+    }
+
+    #[test]
+    fn test_parse_unicode_extension_keywords() {
+        let mut db = mock_terminology_db::MockTerminologyDb::new();
+        db.insert(create_concept("ar", "language", Some("Arabic")));
+        db.insert(create_concept("EG", "region", Some("Egypt")));
+        let parser = Guest::new(db);
+
+        let result = parser.parse(ParseRequest {
+            code: "ar-EG-u-ca-islamic-nu-arab-hc-h12-x-priv".to_string(),
+            properties: None,
+            ..Default::default()
+        });
+        assert!(result.details.is_empty(), "{:?}", result.details);
+        let concept = result.concept.unwrap();
+        assert_eq!(string_property(&concept, "calendar").unwrap(), "islamic");
+        assert_eq!(string_property(&concept, "numbering").unwrap(), "arab");
+        assert_eq!(string_property(&concept, "hourCycle").unwrap(), "h12");
+
+        let result = parser.parse(ParseRequest {
+            code: "ar-EG-u-nu-roman1".to_string(),
+            properties: None,
+            ..Default::default()
+        });
+        assert!(result.concept.is_some());
+        assert!(result
+            .details
+            .iter()
+            .any(|d| d.severity == Severity::Warning && d.key == "numbering"));
+    }
+
+    #[test]
+    fn test_parse_transformed_extension() {
+        let mut db = mock_terminology_db::MockTerminologyDb::new();
+        db.insert(create_concept("und", "language", Some("Undetermined")));
+        db.insert(create_concept("ru", "language", Some("Russian")));
+        db.insert(create_concept("Cyrl", "script", Some("Cyrillic")));
+        db.insert(create_concept("Latn", "script", Some("Latin")));
+        let parser = Guest::new(db);
+
+        let result = parser.parse(ParseRequest {
+            code: "und-Cyrl-t-und-latn-m0-ungegn-2007".to_string(),
+            properties: None,
+            ..Default::default()
+        });
+        assert!(
+            result
+                .details
+                .iter()
+                .all(|d| d.severity == Severity::Information),
+            "{:?}",
+            result.details
+        );
+        let concept = result.concept.unwrap();
+        assert_eq!(
+            string_property(&concept, "transformedSource").unwrap(),
+            "und-Latn"
+        );
+        assert_eq!(
+            property_value(&concept, "transformedSourceScript").unwrap(),
+            coding("script-Latn", Some("Latin"))
+        );
+        assert_eq!(
+            string_property(&concept, "transformMechanism").unwrap(),
+            "ungegn-2007"
+        );
+
+        let result = parser.parse(ParseRequest {
+            code: "ru-t-qq-cyrl-m0-bgn".to_string(),
+            properties: None,
+            ..Default::default()
+        });
+        assert!(result.concept.is_some());
+        assert_eq!(result.details.len(), 1);
+        assert_eq!(result.details[0].key, "transformedSource");
+        assert_eq!(result.details[0].severity, Severity::Error);
+    }
+
+    fn subsumes_in(
+        parser: &Guest<mock_terminology_db::MockTerminologyDb>,
+        ancestor: &str,
+        descendant: &str,
+        modes: &[SubsumesMode],
+    ) -> bool {
+        parser
+            .subsumes(SubsumesRequest {
+                ancestor: ancestor.to_string(),
+                descendant: descendant.to_string(),
+                modes: modes.to_vec(),
+            })
+            .subsumes
+    }
+
+    fn subsumes(ancestor: &str, descendant: &str) -> bool {
+        let parser = Guest::new(mock_terminology_db::MockTerminologyDb::new());
+        subsumes_in(&parser, ancestor, descendant, &[])
+    }
+
+    #[test]
+    fn test_subsumes() {
+        assert!(subsumes("en", "en"));
+        assert!(subsumes("en", "en-US"));
+        assert!(subsumes("zh-Hant", "zh-Hant-TW"));
+        assert!(subsumes("zh-TW", "zh-Hant-TW"));
+        assert!(subsumes("sl-rozaj", "sl-rozaj-biske"));
+        assert!(subsumes("en-US", "en-US-u-co-phonebk-x-priv"));
+        assert!(!subsumes("en-US", "en"));
+        assert!(!subsumes("en", "fr-FR"));
+        assert!(!subsumes("zh-Hans", "zh-Hant-TW"));
+        assert!(!subsumes("sl-biske", "sl-rozaj-biske"));
+    }
+
+    #[test]
+    fn test_subsumes_region_containment() {
+        assert!(subsumes("es-419", "es-MX"));
+        assert!(subsumes("es-419", "es-005"));
+        assert!(subsumes("es-419", "es-AR"));
+        assert!(subsumes("en-150", "en-Latn-GB"));
+        assert!(subsumes("en-001", "en-AU"));
+        assert!(!subsumes("es-419", "es-ES"));
+        assert!(!subsumes("es-MX", "es-419"));
+        assert!(!subsumes("es-419", "es"));
+    }
+
+    #[test]
+    fn test_subsumes_compares_canonical_case() {
+        assert!(subsumes("EN", "en-us"));
+        assert!(subsumes("zh-hant", "ZH-HANT-tw"));
+    }
+
+    #[test]
+    fn test_subsumes_invalid_tag() {
+        assert!(!subsumes("en", "en-US-u-be-abcdefghi"));
+        assert!(!subsumes("e", "en"));
+    }
+    mod mock_terminology_db {
+
+        use super::*;
+        use std::cell::RefCell;
+
+        pub struct MockTerminologyDb {
+            concepts: Vec<Concept>,
+            /// Every lookup made, in order.
+            pub requests: RefCell<Vec<LookupRequest>>,
+        }
+
+        impl MockTerminologyDb {
+            pub fn new() -> Self {
+                MockTerminologyDb {
+                    concepts: Vec::new(),
+                    requests: RefCell::new(Vec::new()),
+                }
+            }
+
+            pub fn insert(&mut self, concept: Concept) {
+                self.concepts.push(concept);
+            }
+        }
+
+        impl TerminologyDb for MockTerminologyDb {
+            fn db_lookup(&self, req: LookupRequest) -> LookupResponse {
+                let mut concept = self.concepts.iter().find(|c| c.code == req.code).cloned();
+                if let (Some(concept), Some(fields)) = (concept.as_mut(), &req.properties) {
+                    concept.properties.retain(|p| fields.contains(&p.code));
+                }
+                if let (Some(concept), false) = (concept.as_mut(), req.designations) {
+                    concept.designations.clear();
                 }
+                self.requests.borrow_mut().push(req);
+                LookupResponse { concept }
             }
 
             fn db_subsumes(&self, _req: SubsumesRequest) -> SubsumesResponse {
@@ -0,0 +1,115 @@
+// Likely subtags, UTS #35 §4.3 "Likely Subtags".
+use crate::likely_subtags::LIKELY_SUBTAGS;
+use crate::LanguageTag;
+
+fn likely(key: &str) -> Option<(&'static str, &'static str, &'static str)> {
+    let index = LIKELY_SUBTAGS
+        .binary_search_by(|(k, _)| (*k).cmp(key))
+        .ok()?;
+    let (_, value) = LIKELY_SUBTAGS[index];
+    let mut subtags = value.split('-');
+    Some((subtags.next()?, subtags.next()?, subtags.next()?))
+}
+
+/// Adds likely subtags to a canonical tag, so `zh-TW` becomes `zh-Hant-TW`
+/// and `en` becomes `en-Latn-US`. The keys are tried in the order
+/// language-script-region, language-script, language-region, language and
+/// `und`-script, as ICU prefers the script, and subtags the tag already has
+/// are kept. Languages CLDR has no likely subtags for give `None`.
+pub fn maximize(tag: &LanguageTag) -> Option<LanguageTag> {
+    if tag.language.is_empty() {
+        return None;
+    }
+    let language = tag.language.as_str();
+    let mut keys = Vec::new();
+    if let (Some(script), Some(region)) = (&tag.script, &tag.region) {
+        keys.push(format!("{}-{}-{}", language, script, region));
+    }
+    if let Some(script) = &tag.script {
+        keys.push(format!("{}-{}", language, script));
+    }
+    if let Some(region) = &tag.region {
+        keys.push(format!("{}-{}", language, region));
+    }
+    keys.push(language.to_string());
+    if let Some(script) = &tag.script {
+        keys.push(format!("und-{}", script));
+    }
+    let (likely_language, likely_script, likely_region) =
+        keys.iter().find_map(|key| likely(key))?;
+    Some(LanguageTag {
+        language: match language {
+            "und" => likely_language.to_string(),
+            _ => language.to_string(),
+        },
+        script: tag.script.clone().or(Some(likely_script.to_string())),
+        region: tag.region.clone().or(Some(likely_region.to_string())),
+        ..tag.clone()
+    })
+}
+
+/// Removes likely subtags: the shortest of language, language-region and
+/// language-script that maximizes to the same tag, so `zh-Hant-TW` becomes
+/// `zh-TW` and `en-Latn-US` becomes `en`.
+pub fn minimize(tag: &LanguageTag) -> Option<LanguageTag> {
+    let max = maximize(tag)?;
+    let candidates = [
+        (None, None),
+        (None, max.region.clone()),
+        (max.script.clone(), None),
+    ];
+    candidates.into_iter().find_map(|(script, region)| {
+        let trial = LanguageTag {
+            script,
+            region,
+            ..max.clone()
+        };
+        (maximize(&trial).as_ref() == Some(&max)).then_some(trial)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar::parse_langtag;
+
+    fn max(code: &str) -> String {
+        maximize(&parse_langtag(code).unwrap()).unwrap().to_string()
+    }
+
+    fn min(code: &str) -> String {
+        minimize(&parse_langtag(code).unwrap()).unwrap().to_string()
+    }
+
+    #[test]
+    fn test_maximize() {
+        assert_eq!(max("en"), "en-Latn-US");
+        assert_eq!(max("zh-TW"), "zh-Hant-TW");
+        assert_eq!(max("zh-Hant"), "zh-Hant-TW");
+        assert_eq!(max("zh"), "zh-Hans-CN");
+        assert_eq!(max("de-CH-1996"), "de-Latn-CH-1996");
+        assert_eq!(max("und-Cyrl"), "ru-Cyrl-RU");
+        assert_eq!(max("und-DE"), "de-Latn-DE");
+        assert_eq!(max("sr-ME"), "sr-Latn-ME");
+        assert_eq!(max("es-US"), "es-Latn-US");
+        assert_eq!(max("yue"), "yue-Hant-HK");
+        assert_eq!(max("und-Hant-US"), "zh-Hant-US");
+        assert_eq!(max("und-Latn-RU"), "krl-Latn-RU");
+        assert!(maximize(&parse_langtag("xyz").unwrap()).is_none());
+    }
+
+    #[test]
+    fn test_minimize() {
+        assert_eq!(min("en-Latn-US"), "en");
+        assert_eq!(min("zh-Hant-TW"), "zh-TW");
+        assert_eq!(min("zh-Hans-CN"), "zh");
+        assert_eq!(min("de-Latn-CH"), "de-CH");
+        assert_eq!(min("sr-Latn"), "sr-Latn");
+        assert_eq!(min("en-US-u-ca-gregory"), "en-u-ca-gregory");
+    }
+
+    #[test]
+    fn test_keys_are_sorted() {
+        assert!(LIKELY_SUBTAGS.windows(2).all(|w| w[0].0 < w[1].0));
+    }
+}
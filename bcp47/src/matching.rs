@@ -0,0 +1,126 @@
+// Language range matching, RFC 4647 §3.
+use crate::LanguageTag;
+
+fn subtags(tag: &LanguageTag) -> Vec<String> {
+    tag.to_string()
+        .split('-')
+        .map(|s| s.to_ascii_lowercase())
+        .collect()
+}
+
+fn range_subtags(range: &str) -> Vec<String> {
+    range.split('-').map(|s| s.to_ascii_lowercase()).collect()
+}
+
+/// Basic filtering (§3.3.1): the range matches a tag that equals it or that
+/// starts with it followed by `-`. The range `*` matches every tag.
+pub fn basic_filter(range: &str, tag: &LanguageTag) -> bool {
+    if range == "*" {
+        return true;
+    }
+    subtags(tag).starts_with(&range_subtags(range))
+}
+
+/// Extended filtering (§3.3.2): `*` matches any sequence of subtags, and
+/// other range subtags must appear in the tag in order, skipping tag
+/// subtags but never across a singleton.
+pub fn extended_filter(range: &str, tag: &LanguageTag) -> bool {
+    let range = range_subtags(range);
+    let tag = subtags(tag);
+    if range[0] != "*" && range[0] != tag[0] {
+        return false;
+    }
+    let (mut r, mut t) = (1, 1);
+    while r < range.len() {
+        if range[r] == "*" {
+            r += 1;
+        } else if t >= tag.len() {
+            return false;
+        } else if range[r] == tag[t] {
+            r += 1;
+            t += 1;
+        } else if tag[t].len() == 1 {
+            return false;
+        } else {
+            t += 1;
+        }
+    }
+    true
+}
+
+/// Lookup (§3.4): the first tag equal to a range, trying each range in
+/// priority order and truncating it from the end until something matches.
+/// A singleton left at the end by truncation goes with the subtag after it,
+/// so `zh-Hant-CN-x-private1` is tried as `zh-Hant-CN`, `zh-Hant` and `zh`.
+/// Wildcards are ignored, and the range `*` alone matches nothing.
+pub fn lookup(ranges: &[&str], tags: &[LanguageTag]) -> Option<usize> {
+    let tags: Vec<Vec<String>> = tags.iter().map(subtags).collect();
+    for range in ranges {
+        let mut range: Vec<String> = range_subtags(range)
+            .into_iter()
+            .filter(|s| s != "*")
+            .collect();
+        while !range.is_empty() {
+            if let Some(index) = tags.iter().position(|tag| *tag == range) {
+                return Some(index);
+            }
+            range.pop();
+            if range.last().is_some_and(|s| s.len() == 1) {
+                range.pop();
+            }
+        }
+    }
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::grammar::parse_langtag;
+
+    fn tag(code: &str) -> LanguageTag {
+        parse_langtag(code).unwrap()
+    }
+
+    #[test]
+    fn test_basic_filter() {
+        assert!(basic_filter("de-de", &tag("de-DE-1996")));
+        assert!(basic_filter("de", &tag("de-Latn-DE")));
+        assert!(basic_filter("*", &tag("fr")));
+        assert!(!basic_filter("de-de", &tag("de-Deva")));
+        assert!(!basic_filter("de-de", &tag("de-Latn-DE")));
+        assert!(!basic_filter("en", &tag("eng")));
+    }
+
+    #[test]
+    fn test_extended_filter() {
+        // The examples of RFC 4647 §3.3.2.
+        for matching in [
+            "de-DE",
+            "de-de",
+            "de-Latn-DE",
+            "de-Latf-DE",
+            "de-DE-x-goethe",
+            "de-Latn-DE-1996",
+            "de-Deva-DE",
+        ] {
+            assert!(extended_filter("de-*-DE", &tag(matching)), "{}", matching);
+            assert!(extended_filter("de-DE", &tag(matching)), "{}", matching);
+        }
+        for other in ["de", "de-x-DE", "de-Deva"] {
+            assert!(!extended_filter("de-*-DE", &tag(other)), "{}", other);
+        }
+        assert!(extended_filter("*-CH", &tag("fr-CH")));
+        assert!(!extended_filter("de-DE", &tag("de-a-abc-DE")));
+    }
+
+    #[test]
+    fn test_lookup() {
+        let tags = [tag("zh"), tag("zh-Hant"), tag("en-US")];
+        assert_eq!(lookup(&["zh-Hant-CN-x-private1"], &tags), Some(1));
+        assert_eq!(lookup(&["zh-Hans-CN"], &tags), Some(0));
+        assert_eq!(lookup(&["fr-FR", "en-US"], &tags), Some(2));
+        assert_eq!(lookup(&["en-*-US"], &tags), Some(2));
+        assert_eq!(lookup(&["fr", "*"], &tags), None);
+    }
+}
@@ -0,0 +1,1133 @@
+// Generated by 03-display-names.js from CLDR 48.0 (ICU 78.2); do not edit.
+
+/// CLDR English names of language, script and region subtags, by subtag
+/// kind and subtag, sorted. Scripts have the form used inside a locale
+/// name, "Simplified" rather than "Simplified Han".
+pub static NAMES: &[(&str, &str, &str)] = &[
+    ("language", "aa", "Afar"),
+    ("language", "ab", "Abkhazian"),
+    ("language", "ace", "Acehnese"),
+    ("language", "ach", "Acoli"),
+    ("language", "ada", "Adangme"),
+    ("language", "ady", "Adyghe"),
+    ("language", "ae", "Avestan"),
+    ("language", "aeb", "Tunisian Arabic"),
+    ("language", "af", "Afrikaans"),
+    ("language", "afh", "Afrihili"),
+    ("language", "agq", "Aghem"),
+    ("language", "ain", "Ainu"),
+    ("language", "ak", "Akan"),
+    ("language", "akk", "Akkadian"),
+    ("language", "akz", "Alabama"),
+    ("language", "ale", "Aleut"),
+    ("language", "aln", "Gheg Albanian"),
+    ("language", "alt", "Southern Altai"),
+    ("language", "am", "Amharic"),
+    ("language", "an", "Aragonese"),
+    ("language", "ang", "Old English"),
+    ("language", "ann", "Obolo"),
+    ("language", "anp", "Angika"),
+    ("language", "ar", "Arabic"),
+    ("language", "arc", "Aramaic"),
+    ("language", "arn", "Mapuche"),
+    ("language", "aro", "Araona"),
+    ("language", "arp", "Arapaho"),
+    ("language", "arq", "Algerian Arabic"),
+    ("language", "ars", "Najdi Arabic"),
+    ("language", "arw", "Arawak"),
+    ("language", "ary", "Moroccan Arabic"),
+    ("language", "arz", "Egyptian Arabic"),
+    ("language", "as", "Assamese"),
+    ("language", "asa", "Asu"),
+    ("language", "ase", "American Sign Language"),
+    ("language", "ast", "Asturian"),
+    ("language", "atj", "Atikamekw"),
+    ("language", "av", "Avaric"),
+    ("language", "avk", "Kotava"),
+    ("language", "awa", "Awadhi"),
+    ("language", "ay", "Aymara"),
+    ("language", "az", "Azerbaijani"),
+    ("language", "ba", "Bashkir"),
+    ("language", "bal", "Baluchi"),
+    ("language", "ban", "Balinese"),
+    ("language", "bar", "Bavarian"),
+    ("language", "bas", "Basaa"),
+    ("language", "bax", "Bamun"),
+    ("language", "bbc", "Batak Toba"),
+    ("language", "bbj", "Ghomala"),
+    ("language", "be", "Belarusian"),
+    ("language", "bej", "Beja"),
+    ("language", "bem", "Bemba"),
+    ("language", "bew", "Betawi"),
+    ("language", "bez", "Bena"),
+    ("language", "bfd", "Bafut"),
+    ("language", "bfq", "Badaga"),
+    ("language", "bg", "Bulgarian"),
+    ("language", "bgc", "Haryanvi"),
+    ("language", "bgn", "Western Balochi"),
+    ("language", "bh", "Bhojpuri"),
+    ("language", "bho", "Bhojpuri"),
+    ("language", "bi", "Bislama"),
+    ("language", "bik", "Bikol"),
+    ("language", "bin", "Bini"),
+    ("language", "bjn", "Banjar"),
+    ("language", "bkm", "Kom"),
+    ("language", "bla", "Siksiká"),
+    ("language", "blo", "Anii"),
+    ("language", "blt", "Tai Dam"),
+    ("language", "bm", "Bambara"),
+    ("language", "bn", "Bangla"),
+    ("language", "bo", "Tibetan"),
+    ("language", "bpy", "Bishnupriya"),
+    ("language", "bqi", "Bakhtiari"),
+    ("language", "br", "Breton"),
+    ("language", "bra", "Braj"),
+    ("language", "brh", "Brahui"),
+    ("language", "brx", "Bodo"),
+    ("language", "bs", "Bosnian"),
+    ("language", "bss", "Akoose"),
+    ("language", "bua", "Buriat"),
+    ("language", "bug", "Buginese"),
+    ("language", "bum", "Bulu"),
+    ("language", "byn", "Blin"),
+    ("language", "byv", "Medumba"),
+    ("language", "ca", "Catalan"),
+    ("language", "cad", "Caddo"),
+    ("language", "car", "Carib"),
+    ("language", "cay", "Cayuga"),
+    ("language", "cch", "Atsam"),
+    ("language", "ccp", "Chakma"),
+    ("language", "ce", "Chechen"),
+    ("language", "ceb", "Cebuano"),
+    ("language", "cgg", "Chiga"),
+    ("language", "ch", "Chamorro"),
+    ("language", "chb", "Chibcha"),
+    ("language", "chg", "Chagatai"),
+    ("language", "chk", "Chuukese"),
+    ("language", "chm", "Mari"),
+    ("language", "chn", "Chinook Jargon"),
+    ("language", "cho", "Choctaw"),
+    ("language", "chp", "Chipewyan"),
+    ("language", "chr", "Cherokee"),
+    ("language", "chy", "Cheyenne"),
+    ("language", "cic", "Chickasaw"),
+    ("language", "ckb", "Central Kurdish"),
+    ("language", "clc", "Chilcotin"),
+    ("language", "co", "Corsican"),
+    ("language", "cop", "Coptic"),
+    ("language", "cps", "Capiznon"),
+    ("language", "cr", "Cree"),
+    ("language", "crg", "Michif"),
+    ("language", "crh", "Crimean Tatar"),
+    ("language", "crj", "Southern East Cree"),
+    ("language", "crk", "Plains Cree"),
+    ("language", "crl", "Northern East Cree"),
+    ("language", "crm", "Moose Cree"),
+    ("language", "crr", "Carolina Algonquian"),
+    ("language", "crs", "Seselwa Creole French"),
+    ("language", "cs", "Czech"),
+    ("language", "csb", "Kashubian"),
+    ("language", "csw", "Swampy Cree"),
+    ("language", "cu", "Church Slavic"),
+    ("language", "cv", "Chuvash"),
+    ("language", "cy", "Welsh"),
+    ("language", "da", "Danish"),
+    ("language", "dak", "Dakota"),
+    ("language", "dar", "Dargwa"),
+    ("language", "dav", "Taita"),
+    ("language", "de", "German"),
+    ("language", "del", "Delaware"),
+    ("language", "den", "Slave"),
+    ("language", "dgr", "Dogrib"),
+    ("language", "din", "Dinka"),
+    ("language", "dje", "Zarma"),
+    ("language", "doi", "Dogri"),
+    ("language", "dsb", "Lower Sorbian"),
+    ("language", "dtp", "Central Dusun"),
+    ("language", "dua", "Duala"),
+    ("language", "dum", "Middle Dutch"),
+    ("language", "dv", "Divehi"),
+    ("language", "dyo", "Jola-Fonyi"),
+    ("language", "dyu", "Dyula"),
+    ("language", "dz", "Dzongkha"),
+    ("language", "dzg", "Dazaga"),
+    ("language", "ebu", "Embu"),
+    ("language", "ee", "Ewe"),
+    ("language", "efi", "Efik"),
+    ("language", "egl", "Emilian"),
+    ("language", "egy", "Ancient Egyptian"),
+    ("language", "eka", "Ekajuk"),
+    ("language", "el", "Greek"),
+    ("language", "elx", "Elamite"),
+    ("language", "en", "English"),
+    ("language", "enm", "Middle English"),
+    ("language", "eo", "Esperanto"),
+    ("language", "es", "Spanish"),
+    ("language", "esu", "Central Yupik"),
+    ("language", "et", "Estonian"),
+    ("language", "eu", "Basque"),
+    ("language", "ewo", "Ewondo"),
+    ("language", "ext", "Extremaduran"),
+    ("language", "fa", "Persian"),
+    ("language", "fan", "Fang"),
+    ("language", "ff", "Fula"),
+    ("language", "fi", "Finnish"),
+    ("language", "fil", "Filipino"),
+    ("language", "fit", "Tornedalen Finnish"),
+    ("language", "fj", "Fijian"),
+    ("language", "fo", "Faroese"),
+    ("language", "fon", "Fon"),
+    ("language", "fr", "French"),
+    ("language", "frc", "Cajun French"),
+    ("language", "frm", "Middle French"),
+    ("language", "fro", "Old French"),
+    ("language", "frp", "Arpitan"),
+    ("language", "frr", "Northern Frisian"),
+    ("language", "frs", "Eastern Frisian"),
+    ("language", "fur", "Friulian"),
+    ("language", "fy", "Western Frisian"),
+    ("language", "ga", "Irish"),
+    ("language", "gaa", "Ga"),
+    ("language", "gag", "Gagauz"),
+    ("language", "gan", "Gan Chinese"),
+    ("language", "gay", "Gayo"),
+    ("language", "gba", "Gbaya"),
+    ("language", "gbz", "Zoroastrian Dari"),
+    ("language", "gd", "Scottish Gaelic"),
+    ("language", "gez", "Geez"),
+    ("language", "gil", "Gilbertese"),
+    ("language", "gl", "Galician"),
+    ("language", "glk", "Gilaki"),
+    ("language", "gmh", "Middle High German"),
+    ("language", "gn", "Guarani"),
+    ("language", "goh", "Old High German"),
+    ("language", "gon", "Gondi"),
+    ("language", "gor", "Gorontalo"),
+    ("language", "got", "Gothic"),
+    ("language", "grb", "Grebo"),
+    ("language", "grc", "Ancient Greek"),
+    ("language", "gsw", "Swiss German"),
+    ("language", "gu", "Gujarati"),
+    ("language", "guc", "Wayuu"),
+    ("language", "gur", "Frafra"),
+    ("language", "guz", "Gusii"),
+    ("language", "gv", "Manx"),
+    ("language", "gwi", "Gwichʼin"),
+    ("language", "ha", "Hausa"),
+    ("language", "hai", "Haida"),
+    ("language", "hak", "Hakka Chinese"),
+    ("language", "haw", "Hawaiian"),
+    ("language", "hax", "Southern Haida"),
+    ("language", "he", "Hebrew"),
+    ("language", "hi", "Hindi"),
+    ("language", "hif", "Fiji Hindi"),
+    ("language", "hil", "Hiligaynon"),
+    ("language", "hit", "Hittite"),
+    ("language", "hmn", "Hmong"),
+    ("language", "hnj", "Hmong Njua"),
+    ("language", "ho", "Hiri Motu"),
+    ("language", "hr", "Croatian"),
+    ("language", "hsb", "Upper Sorbian"),
+    ("language", "hsn", "Xiang Chinese"),
+    ("language", "ht", "Haitian Creole"),
+    ("language", "hu", "Hungarian"),
+    ("language", "hup", "Hupa"),
+    ("language", "hur", "Halkomelem"),
+    ("language", "hy", "Armenian"),
+    ("language", "hz", "Herero"),
+    ("language", "ia", "Interlingua"),
+    ("language", "iba", "Iban"),
+    ("language", "ibb", "Ibibio"),
+    ("language", "id", "Indonesian"),
+    ("language", "ie", "Interlingue"),
+    ("language", "ig", "Igbo"),
+    ("language", "ii", "Sichuan Yi"),
+    ("language", "ik", "Inupiaq"),
+    ("language", "ikt", "Western Canadian Inuktitut"),
+    ("language", "ilo", "Iloko"),
+    ("language", "inh", "Ingush"),
+    ("language", "io", "Ido"),
+    ("language", "is", "Icelandic"),
+    ("language", "it", "Italian"),
+    ("language", "iu", "Inuktitut"),
+    ("language", "izh", "Ingrian"),
+    ("language", "ja", "Japanese"),
+    ("language", "jam", "Jamaican Creole English"),
+    ("language", "jbo", "Lojban"),
+    ("language", "jgo", "Ngomba"),
+    ("language", "jmc", "Machame"),
+    ("language", "jpr", "Judeo-Persian"),
+    ("language", "jrb", "Judeo-Arabic"),
+    ("language", "jut", "Jutish"),
+    ("language", "jv", "Javanese"),
+    ("language", "ka", "Georgian"),
+    ("language", "kaa", "Kara-Kalpak"),
+    ("language", "kab", "Kabyle"),
+    ("language", "kac", "Kachin"),
+    ("language", "kaj", "Jju"),
+    ("language", "kam", "Kamba"),
+    ("language", "kaw", "Kawi"),
+    ("language", "kbd", "Kabardian"),
+    ("language", "kbl", "Kanembu"),
+    ("language", "kcg", "Tyap"),
+    ("language", "kde", "Makonde"),
+    ("language", "kea", "Kabuverdianu"),
+    ("language", "kek", "Qʼeqchiʼ"),
+    ("language", "ken", "Kenyang"),
+    ("language", "kfo", "Koro"),
+    ("language", "kg", "Kongo"),
+    ("language", "kgp", "Kaingang"),
+    ("language", "kha", "Khasi"),
+    ("language", "kho", "Khotanese"),
+    ("language", "khq", "Koyra Chiini"),
+    ("language", "khw", "Khowar"),
+    ("language", "ki", "Kikuyu"),
+    ("language", "kiu", "Kirmanjki"),
+    ("language", "kj", "Kuanyama"),
+    ("language", "kk", "Kazakh"),
+    ("language", "kkj", "Kako"),
+    ("language", "kl", "Kalaallisut"),
+    ("language", "kln", "Kalenjin"),
+    ("language", "km", "Khmer"),
+    ("language", "kmb", "Kimbundu"),
+    ("language", "kn", "Kannada"),
+    ("language", "ko", "Korean"),
+    ("language", "koi", "Komi-Permyak"),
+    ("language", "kok", "Konkani"),
+    ("language", "kos", "Kosraean"),
+    ("language", "kpe", "Kpelle"),
+    ("language", "kr", "Kanuri"),
+    ("language", "krc", "Karachay-Balkar"),
+    ("language", "kri", "Krio"),
+    ("language", "krj", "Kinaray-a"),
+    ("language", "krl", "Karelian"),
+    ("language", "kru", "Kurukh"),
+    ("language", "ks", "Kashmiri"),
+    ("language", "ksb", "Shambala"),
+    ("language", "ksf", "Bafia"),
+    ("language", "ksh", "Colognian"),
+    ("language", "ku", "Kurdish"),
+    ("language", "kum", "Kumyk"),
+    ("language", "kut", "Kutenai"),
+    ("language", "kv", "Komi"),
+    ("language", "kw", "Cornish"),
+    ("language", "kwk", "Kwakʼwala"),
+    ("language", "kxv", "Kuvi"),
+    ("language", "ky", "Kyrgyz"),
+    ("language", "la", "Latin"),
+    ("language", "lad", "Ladino"),
+    ("language", "lag", "Langi"),
+    ("language", "lah", "Western Panjabi"),
+    ("language", "lam", "Lamba"),
+    ("language", "lb", "Luxembourgish"),
+    ("language", "lez", "Lezghian"),
+    ("language", "lfn", "Lingua Franca Nova"),
+    ("language", "lg", "Ganda"),
+    ("language", "li", "Limburgish"),
+    ("language", "lij", "Ligurian"),
+    ("language", "lil", "Lillooet"),
+    ("language", "liv", "Livonian"),
+    ("language", "lkt", "Lakota"),
+    ("language", "lmo", "Lombard"),
+    ("language", "ln", "Lingala"),
+    ("language", "lo", "Lao"),
+    ("language", "lol", "Mongo"),
+    ("language", "lou", "Louisiana Creole"),
+    ("language", "loz", "Lozi"),
+    ("language", "lrc", "Northern Luri"),
+    ("language", "lsm", "Saamia"),
+    ("language", "lt", "Lithuanian"),
+    ("language", "ltg", "Latgalian"),
+    ("language", "lu", "Luba-Katanga"),
+    ("language", "lua", "Luba-Lulua"),
+    ("language", "lui", "Luiseno"),
+    ("language", "lun", "Lunda"),
+    ("language", "luo", "Luo"),
+    ("language", "lus", "Mizo"),
+    ("language", "luy", "Luyia"),
+    ("language", "lv", "Latvian"),
+    ("language", "lzh", "Literary Chinese"),
+    ("language", "lzz", "Laz"),
+    ("language", "mad", "Madurese"),
+    ("language", "maf", "Mafa"),
+    ("language", "mag", "Magahi"),
+    ("language", "mai", "Maithili"),
+    ("language", "mak", "Makasar"),
+    ("language", "man", "Mandingo"),
+    ("language", "mas", "Masai"),
+    ("language", "mde", "Maba"),
+    ("language", "mdf", "Moksha"),
+    ("language", "mdr", "Mandar"),
+    ("language", "men", "Mende"),
+    ("language", "mer", "Meru"),
+    ("language", "mfe", "Morisyen"),
+    ("language", "mg", "Malagasy"),
+    ("language", "mga", "Middle Irish"),
+    ("language", "mgh", "Makhuwa-Meetto"),
+    ("language", "mgo", "Metaʼ"),
+    ("language", "mh", "Marshallese"),
+    ("language", "mi", "Māori"),
+    ("language", "mic", "Mi'kmaw"),
+    ("language", "min", "Minangkabau"),
+    ("language", "mk", "Macedonian"),
+    ("language", "ml", "Malayalam"),
+    ("language", "mn", "Mongolian"),
+    ("language", "mnc", "Manchu"),
+    ("language", "mni", "Manipuri"),
+    ("language", "moe", "Innu-aimun"),
+    ("language", "moh", "Mohawk"),
+    ("language", "mos", "Mossi"),
+    ("language", "mr", "Marathi"),
+    ("language", "mrj", "Western Mari"),
+    ("language", "ms", "Malay"),
+    ("language", "mt", "Maltese"),
+    ("language", "mua", "Mundang"),
+    ("language", "mul", "Multiple languages"),
+    ("language", "mus", "Muscogee"),
+    ("language", "mwl", "Mirandese"),
+    ("language", "mwr", "Marwari"),
+    ("language", "mwv", "Mentawai"),
+    ("language", "my", "Burmese"),
+    ("language", "mye", "Myene"),
+    ("language", "myv", "Erzya"),
+    ("language", "mzn", "Mazanderani"),
+    ("language", "na", "Nauru"),
+    ("language", "nan", "Min Nan Chinese"),
+    ("language", "nap", "Neapolitan"),
+    ("language", "naq", "Nama"),
+    ("language", "nb", "Norwegian Bokmål"),
+    ("language", "nd", "North Ndebele"),
+    ("language", "nds", "Low German"),
+    ("language", "ne", "Nepali"),
+    ("language", "new", "Newari"),
+    ("language", "ng", "Ndonga"),
+    ("language", "nia", "Nias"),
+    ("language", "niu", "Niuean"),
+    ("language", "njo", "Ao Naga"),
+    ("language", "nl", "Dutch"),
+    ("language", "nmg", "Kwasio"),
+    ("language", "nn", "Norwegian Nynorsk"),
+    ("language", "nnh", "Ngiemboon"),
+    ("language", "no", "Norwegian"),
+    ("language", "nog", "Nogai"),
+    ("language", "non", "Old Norse"),
+    ("language", "nov", "Novial"),
+    ("language", "nqo", "N’Ko"),
+    ("language", "nr", "South Ndebele"),
+    ("language", "nso", "Northern Sotho"),
+    ("language", "nus", "Nuer"),
+    ("language", "nv", "Navajo"),
+    ("language", "nwc", "Classical Newari"),
+    ("language", "ny", "Nyanja"),
+    ("language", "nym", "Nyamwezi"),
+    ("language", "nyn", "Nyankole"),
+    ("language", "nyo", "Nyoro"),
+    ("language", "nzi", "Nzima"),
+    ("language", "oc", "Occitan"),
+    ("language", "oj", "Ojibwa"),
+    ("language", "ojb", "Northwestern Ojibwa"),
+    ("language", "ojc", "Central Ojibwa"),
+    ("language", "ojs", "Oji-Cree"),
+    ("language", "ojw", "Western Ojibwa"),
+    ("language", "oka", "Okanagan"),
+    ("language", "om", "Oromo"),
+    ("language", "or", "Odia"),
+    ("language", "os", "Ossetic"),
+    ("language", "osa", "Osage"),
+    ("language", "ota", "Ottoman Turkish"),
+    ("language", "pa", "Punjabi"),
+    ("language", "pag", "Pangasinan"),
+    ("language", "pal", "Pahlavi"),
+    ("language", "pam", "Pampanga"),
+    ("language", "pap", "Papiamento"),
+    ("language", "pau", "Palauan"),
+    ("language", "pcd", "Picard"),
+    ("language", "pcm", "Nigerian Pidgin"),
+    ("language", "pdc", "Pennsylvania German"),
+    ("language", "pdt", "Plautdietsch"),
+    ("language", "peo", "Old Persian"),
+    ("language", "pfl", "Palatine German"),
+    ("language", "phn", "Phoenician"),
+    ("language", "pi", "Pali"),
+    ("language", "pis", "Pijin"),
+    ("language", "pl", "Polish"),
+    ("language", "pms", "Piedmontese"),
+    ("language", "pnt", "Pontic"),
+    ("language", "pon", "Pohnpeian"),
+    ("language", "pqm", "Maliseet-Passamaquoddy"),
+    ("language", "prg", "Prussian"),
+    ("language", "pro", "Old Provençal"),
+    ("language", "ps", "Pashto"),
+    ("language", "pt", "Portuguese"),
+    ("language", "qu", "Quechua"),
+    ("language", "quc", "Kʼicheʼ"),
+    ("language", "qug", "Chimborazo Highland Quichua"),
+    ("language", "raj", "Rajasthani"),
+    ("language", "rap", "Rapanui"),
+    ("language", "rar", "Rarotongan"),
+    ("language", "rgn", "Romagnol"),
+    ("language", "rhg", "Rohingya"),
+    ("language", "rif", "Riffian"),
+    ("language", "rm", "Romansh"),
+    ("language", "rn", "Rundi"),
+    ("language", "ro", "Romanian"),
+    ("language", "rof", "Rombo"),
+    ("language", "rom", "Romany"),
+    ("language", "rtm", "Rotuman"),
+    ("language", "ru", "Russian"),
+    ("language", "rue", "Rusyn"),
+    ("language", "rug", "Roviana"),
+    ("language", "rup", "Aromanian"),
+    ("language", "rw", "Kinyarwanda"),
+    ("language", "rwk", "Rwa"),
+    ("language", "sa", "Sanskrit"),
+    ("language", "sad", "Sandawe"),
+    ("language", "sah", "Yakut"),
+    ("language", "sam", "Samaritan Aramaic"),
+    ("language", "saq", "Samburu"),
+    ("language", "sas", "Sasak"),
+    ("language", "sat", "Santali"),
+    ("language", "saz", "Saurashtra"),
+    ("language", "sba", "Ngambay"),
+    ("language", "sbp", "Sangu"),
+    ("language", "sc", "Sardinian"),
+    ("language", "scn", "Sicilian"),
+    ("language", "sco", "Scots"),
+    ("language", "sd", "Sindhi"),
+    ("language", "sdc", "Sassarese Sardinian"),
+    ("language", "sdh", "Southern Kurdish"),
+    ("language", "se", "Northern Sami"),
+    ("language", "see", "Seneca"),
+    ("language", "seh", "Sena"),
+    ("language", "sei", "Seri"),
+    ("language", "sel", "Selkup"),
+    ("language", "ses", "Koyraboro Senni"),
+    ("language", "sg", "Sango"),
+    ("language", "sga", "Old Irish"),
+    ("language", "sgs", "Samogitian"),
+    ("language", "shi", "Tachelhit"),
+    ("language", "shn", "Shan"),
+    ("language", "shu", "Chadian Arabic"),
+    ("language", "si", "Sinhala"),
+    ("language", "sid", "Sidamo"),
+    ("language", "sk", "Slovak"),
+    ("language", "sl", "Slovenian"),
+    ("language", "slh", "Southern Lushootseed"),
+    ("language", "sli", "Lower Silesian"),
+    ("language", "sly", "Selayar"),
+    ("language", "sm", "Samoan"),
+    ("language", "sma", "Southern Sami"),
+    ("language", "smj", "Lule Sami"),
+    ("language", "smn", "Inari Sami"),
+    ("language", "sms", "Skolt Sami"),
+    ("language", "sn", "Shona"),
+    ("language", "snk", "Soninke"),
+    ("language", "so", "Somali"),
+    ("language", "sog", "Sogdien"),
+    ("language", "sq", "Albanian"),
+    ("language", "sr", "Serbian"),
+    ("language", "srn", "Sranan Tongo"),
+    ("language", "srr", "Serer"),
+    ("language", "ss", "Swati"),
+    ("language", "ssy", "Saho"),
+    ("language", "st", "Southern Sotho"),
+    ("language", "stq", "Saterland Frisian"),
+    ("language", "str", "Straits Salish"),
+    ("language", "su", "Sundanese"),
+    ("language", "suk", "Sukuma"),
+    ("language", "sus", "Susu"),
+    ("language", "sux", "Sumerian"),
+    ("language", "sv", "Swedish"),
+    ("language", "sw", "Swahili"),
+    ("language", "swb", "Comorian"),
+    ("language", "syc", "Classical Syriac"),
+    ("language", "syr", "Syriac"),
+    ("language", "szl", "Silesian"),
+    ("language", "ta", "Tamil"),
+    ("language", "tce", "Southern Tutchone"),
+    ("language", "tcy", "Tulu"),
+    ("language", "te", "Telugu"),
+    ("language", "tem", "Timne"),
+    ("language", "teo", "Teso"),
+    ("language", "ter", "Tereno"),
+    ("language", "tet", "Tetum"),
+    ("language", "tg", "Tajik"),
+    ("language", "tgx", "Tagish"),
+    ("language", "th", "Thai"),
+    ("language", "tht", "Tahltan"),
+    ("language", "ti", "Tigrinya"),
+    ("language", "tig", "Tigre"),
+    ("language", "tiv", "Tiv"),
+    ("language", "tk", "Turkmen"),
+    ("language", "tkl", "Tokelauan"),
+    ("language", "tkr", "Tsakhur"),
+    ("language", "tlh", "Klingon"),
+    ("language", "tli", "Tlingit"),
+    ("language", "tly", "Talysh"),
+    ("language", "tmh", "Tamashek"),
+    ("language", "tn", "Tswana"),
+    ("language", "to", "Tongan"),
+    ("language", "tog", "Nyasa Tonga"),
+    ("language", "tok", "Toki Pona"),
+    ("language", "tpi", "Tok Pisin"),
+    ("language", "tr", "Turkish"),
+    ("language", "tru", "Turoyo"),
+    ("language", "trv", "Taroko"),
+    ("language", "trw", "Torwali"),
+    ("language", "ts", "Tsonga"),
+    ("language", "tsd", "Tsakonian"),
+    ("language", "tsi", "Tsimshian"),
+    ("language", "tt", "Tatar"),
+    ("language", "ttm", "Northern Tutchone"),
+    ("language", "ttt", "Muslim Tat"),
+    ("language", "tum", "Tumbuka"),
+    ("language", "tvl", "Tuvalu"),
+    ("language", "tw", "Akan"),
+    ("language", "twq", "Tasawaq"),
+    ("language", "ty", "Tahitian"),
+    ("language", "tyv", "Tuvinian"),
+    ("language", "tzm", "Central Atlas Tamazight"),
+    ("language", "udm", "Udmurt"),
+    ("language", "ug", "Uyghur"),
+    ("language", "uga", "Ugaritic"),
+    ("language", "uk", "Ukrainian"),
+    ("language", "umb", "Umbundu"),
+    ("language", "ur", "Urdu"),
+    ("language", "uz", "Uzbek"),
+    ("language", "vai", "Vai"),
+    ("language", "ve", "Venda"),
+    ("language", "vec", "Venetian"),
+    ("language", "vep", "Veps"),
+    ("language", "vi", "Vietnamese"),
+    ("language", "vls", "West Flemish"),
+    ("language", "vmf", "Main-Franconian"),
+    ("language", "vmw", "Makhuwa"),
+    ("language", "vo", "Volapük"),
+    ("language", "vot", "Votic"),
+    ("language", "vro", "Võro"),
+    ("language", "vun", "Vunjo"),
+    ("language", "wa", "Walloon"),
+    ("language", "wae", "Walser"),
+    ("language", "wal", "Wolaytta"),
+    ("language", "war", "Waray"),
+    ("language", "was", "Washo"),
+    ("language", "wbp", "Warlpiri"),
+    ("language", "wo", "Wolof"),
+    ("language", "wuu", "Wu Chinese"),
+    ("language", "xal", "Kalmyk"),
+    ("language", "xh", "Xhosa"),
+    ("language", "xmf", "Mingrelian"),
+    ("language", "xnr", "Kangri"),
+    ("language", "xog", "Soga"),
+    ("language", "yao", "Yao"),
+    ("language", "yap", "Yapese"),
+    ("language", "yav", "Yangben"),
+    ("language", "ybb", "Yemba"),
+    ("language", "yi", "Yiddish"),
+    ("language", "yo", "Yoruba"),
+    ("language", "yrl", "Nheengatu"),
+    ("language", "yue", "Cantonese"),
+    ("language", "za", "Zhuang"),
+    ("language", "zap", "Zapotec"),
+    ("language", "zbl", "Blissymbols"),
+    ("language", "zea", "Zeelandic"),
+    ("language", "zen", "Zenaga"),
+    ("language", "zgh", "Standard Moroccan Tamazight"),
+    ("language", "zh", "Chinese"),
+    ("language", "zu", "Zulu"),
+    ("language", "zun", "Zuni"),
+    ("language", "zxx", "No linguistic content"),
+    ("language", "zza", "Zaza"),
+    ("region", "001", "world"),
+    ("region", "002", "Africa"),
+    ("region", "003", "North America"),
+    ("region", "005", "South America"),
+    ("region", "009", "Oceania"),
+    ("region", "011", "Western Africa"),
+    ("region", "013", "Central America"),
+    ("region", "014", "Eastern Africa"),
+    ("region", "015", "Northern Africa"),
+    ("region", "017", "Middle Africa"),
+    ("region", "018", "Southern Africa"),
+    ("region", "019", "Americas"),
+    ("region", "021", "Northern America"),
+    ("region", "029", "Caribbean"),
+    ("region", "030", "Eastern Asia"),
+    ("region", "034", "Southern Asia"),
+    ("region", "035", "Southeast Asia"),
+    ("region", "039", "Southern Europe"),
+    ("region", "053", "Australasia"),
+    ("region", "054", "Melanesia"),
+    ("region", "057", "Micronesian Region"),
+    ("region", "061", "Polynesia"),
+    ("region", "142", "Asia"),
+    ("region", "143", "Central Asia"),
+    ("region", "145", "Western Asia"),
+    ("region", "150", "Europe"),
+    ("region", "151", "Eastern Europe"),
+    ("region", "154", "Northern Europe"),
+    ("region", "155", "Western Europe"),
+    ("region", "202", "Sub-Saharan Africa"),
+    ("region", "419", "Latin America"),
+    ("region", "AC", "Ascension Island"),
+    ("region", "AD", "Andorra"),
+    ("region", "AE", "United Arab Emirates"),
+    ("region", "AF", "Afghanistan"),
+    ("region", "AG", "Antigua & Barbuda"),
+    ("region", "AI", "Anguilla"),
+    ("region", "AL", "Albania"),
+    ("region", "AM", "Armenia"),
+    ("region", "AO", "Angola"),
+    ("region", "AQ", "Antarctica"),
+    ("region", "AR", "Argentina"),
+    ("region", "AS", "American Samoa"),
+    ("region", "AT", "Austria"),
+    ("region", "AU", "Australia"),
+    ("region", "AW", "Aruba"),
+    ("region", "AX", "Åland Islands"),
+    ("region", "AZ", "Azerbaijan"),
+    ("region", "BA", "Bosnia & Herzegovina"),
+    ("region", "BB", "Barbados"),
+    ("region", "BD", "Bangladesh"),
+    ("region", "BE", "Belgium"),
+    ("region", "BF", "Burkina Faso"),
+    ("region", "BG", "Bulgaria"),
+    ("region", "BH", "Bahrain"),
+    ("region", "BI", "Burundi"),
+    ("region", "BJ", "Benin"),
+    ("region", "BL", "St. Barthélemy"),
+    ("region", "BM", "Bermuda"),
+    ("region", "BN", "Brunei"),
+    ("region", "BO", "Bolivia"),
+    ("region", "BQ", "Caribbean Netherlands"),
+    ("region", "BR", "Brazil"),
+    ("region", "BS", "Bahamas"),
+    ("region", "BT", "Bhutan"),
+    ("region", "BV", "Bouvet Island"),
+    ("region", "BW", "Botswana"),
+    ("region", "BY", "Belarus"),
+    ("region", "BZ", "Belize"),
+    ("region", "CA", "Canada"),
+    ("region", "CC", "Cocos (Keeling) Islands"),
+    ("region", "CD", "Congo - Kinshasa"),
+    ("region", "CF", "Central African Republic"),
+    ("region", "CG", "Congo - Brazzaville"),
+    ("region", "CH", "Switzerland"),
+    ("region", "CI", "Côte d’Ivoire"),
+    ("region", "CK", "Cook Islands"),
+    ("region", "CL", "Chile"),
+    ("region", "CM", "Cameroon"),
+    ("region", "CN", "China"),
+    ("region", "CO", "Colombia"),
+    ("region", "CP", "Clipperton Island"),
+    ("region", "CQ", "Sark"),
+    ("region", "CR", "Costa Rica"),
+    ("region", "CU", "Cuba"),
+    ("region", "CV", "Cape Verde"),
+    ("region", "CW", "Curaçao"),
+    ("region", "CX", "Christmas Island"),
+    ("region", "CY", "Cyprus"),
+    ("region", "CZ", "Czechia"),
+    ("region", "DE", "Germany"),
+    ("region", "DG", "Diego Garcia"),
+    ("region", "DJ", "Djibouti"),
+    ("region", "DK", "Denmark"),
+    ("region", "DM", "Dominica"),
+    ("region", "DO", "Dominican Republic"),
+    ("region", "DZ", "Algeria"),
+    ("region", "EA", "Ceuta & Melilla"),
+    ("region", "EC", "Ecuador"),
+    ("region", "EE", "Estonia"),
+    ("region", "EG", "Egypt"),
+    ("region", "EH", "Western Sahara"),
+    ("region", "ER", "Eritrea"),
+    ("region", "ES", "Spain"),
+    ("region", "ET", "Ethiopia"),
+    ("region", "EU", "European Union"),
+    ("region", "EZ", "Eurozone"),
+    ("region", "FI", "Finland"),
+    ("region", "FJ", "Fiji"),
+    ("region", "FK", "Falkland Islands"),
+    ("region", "FM", "Micronesia"),
+    ("region", "FO", "Faroe Islands"),
+    ("region", "FR", "France"),
+    ("region", "GA", "Gabon"),
+    ("region", "GB", "United Kingdom"),
+    ("region", "GD", "Grenada"),
+    ("region", "GE", "Georgia"),
+    ("region", "GF", "French Guiana"),
+    ("region", "GG", "Guernsey"),
+    ("region", "GH", "Ghana"),
+    ("region", "GI", "Gibraltar"),
+    ("region", "GL", "Greenland"),
+    ("region", "GM", "Gambia"),
+    ("region", "GN", "Guinea"),
+    ("region", "GP", "Guadeloupe"),
+    ("region", "GQ", "Equatorial Guinea"),
+    ("region", "GR", "Greece"),
+    ("region", "GS", "South Georgia & South Sandwich Islands"),
+    ("region", "GT", "Guatemala"),
+    ("region", "GU", "Guam"),
+    ("region", "GW", "Guinea-Bissau"),
+    ("region", "GY", "Guyana"),
+    ("region", "HK", "Hong Kong SAR China"),
+    ("region", "HM", "Heard & McDonald Islands"),
+    ("region", "HN", "Honduras"),
+    ("region", "HR", "Croatia"),
+    ("region", "HT", "Haiti"),
+    ("region", "HU", "Hungary"),
+    ("region", "IC", "Canary Islands"),
+    ("region", "ID", "Indonesia"),
+    ("region", "IE", "Ireland"),
+    ("region", "IL", "Israel"),
+    ("region", "IM", "Isle of Man"),
+    ("region", "IN", "India"),
+    ("region", "IO", "British Indian Ocean Territory"),
+    ("region", "IQ", "Iraq"),
+    ("region", "IR", "Iran"),
+    ("region", "IS", "Iceland"),
+    ("region", "IT", "Italy"),
+    ("region", "JE", "Jersey"),
+    ("region", "JM", "Jamaica"),
+    ("region", "JO", "Jordan"),
+    ("region", "JP", "Japan"),
+    ("region", "KE", "Kenya"),
+    ("region", "KG", "Kyrgyzstan"),
+    ("region", "KH", "Cambodia"),
+    ("region", "KI", "Kiribati"),
+    ("region", "KM", "Comoros"),
+    ("region", "KN", "St. Kitts & Nevis"),
+    ("region", "KP", "North Korea"),
+    ("region", "KR", "South Korea"),
+    ("region", "KW", "Kuwait"),
+    ("region", "KY", "Cayman Islands"),
+    ("region", "KZ", "Kazakhstan"),
+    ("region", "LA", "Laos"),
+    ("region", "LB", "Lebanon"),
+    ("region", "LC", "St. Lucia"),
+    ("region", "LI", "Liechtenstein"),
+    ("region", "LK", "Sri Lanka"),
+    ("region", "LR", "Liberia"),
+    ("region", "LS", "Lesotho"),
+    ("region", "LT", "Lithuania"),
+    ("region", "LU", "Luxembourg"),
+    ("region", "LV", "Latvia"),
+    ("region", "LY", "Libya"),
+    ("region", "MA", "Morocco"),
+    ("region", "MC", "Monaco"),
+    ("region", "MD", "Moldova"),
+    ("region", "ME", "Montenegro"),
+    ("region", "MF", "St. Martin"),
+    ("region", "MG", "Madagascar"),
+    ("region", "MH", "Marshall Islands"),
+    ("region", "MK", "North Macedonia"),
+    ("region", "ML", "Mali"),
+    ("region", "MM", "Myanmar (Burma)"),
+    ("region", "MN", "Mongolia"),
+    ("region", "MO", "Macao SAR China"),
+    ("region", "MP", "Northern Mariana Islands"),
+    ("region", "MQ", "Martinique"),
+    ("region", "MR", "Mauritania"),
+    ("region", "MS", "Montserrat"),
+    ("region", "MT", "Malta"),
+    ("region", "MU", "Mauritius"),
+    ("region", "MV", "Maldives"),
+    ("region", "MW", "Malawi"),
+    ("region", "MX", "Mexico"),
+    ("region", "MY", "Malaysia"),
+    ("region", "MZ", "Mozambique"),
+    ("region", "NA", "Namibia"),
+    ("region", "NC", "New Caledonia"),
+    ("region", "NE", "Niger"),
+    ("region", "NF", "Norfolk Island"),
+    ("region", "NG", "Nigeria"),
+    ("region", "NI", "Nicaragua"),
+    ("region", "NL", "Netherlands"),
+    ("region", "NO", "Norway"),
+    ("region", "NP", "Nepal"),
+    ("region", "NR", "Nauru"),
+    ("region", "NU", "Niue"),
+    ("region", "NZ", "New Zealand"),
+    ("region", "OM", "Oman"),
+    ("region", "PA", "Panama"),
+    ("region", "PE", "Peru"),
+    ("region", "PF", "French Polynesia"),
+    ("region", "PG", "Papua New Guinea"),
+    ("region", "PH", "Philippines"),
+    ("region", "PK", "Pakistan"),
+    ("region", "PL", "Poland"),
+    ("region", "PM", "St. Pierre & Miquelon"),
+    ("region", "PN", "Pitcairn Islands"),
+    ("region", "PR", "Puerto Rico"),
+    ("region", "PS", "Palestinian Territories"),
+    ("region", "PT", "Portugal"),
+    ("region", "PW", "Palau"),
+    ("region", "PY", "Paraguay"),
+    ("region", "QA", "Qatar"),
+    ("region", "RE", "Réunion"),
+    ("region", "RO", "Romania"),
+    ("region", "RS", "Serbia"),
+    ("region", "RU", "Russia"),
+    ("region", "RW", "Rwanda"),
+    ("region", "SA", "Saudi Arabia"),
+    ("region", "SB", "Solomon Islands"),
+    ("region", "SC", "Seychelles"),
+    ("region", "SD", "Sudan"),
+    ("region", "SE", "Sweden"),
+    ("region", "SG", "Singapore"),
+    ("region", "SH", "St. Helena"),
+    ("region", "SI", "Slovenia"),
+    ("region", "SJ", "Svalbard & Jan Mayen"),
+    ("region", "SK", "Slovakia"),
+    ("region", "SL", "Sierra Leone"),
+    ("region", "SM", "San Marino"),
+    ("region", "SN", "Senegal"),
+    ("region", "SO", "Somalia"),
+    ("region", "SR", "Suriname"),
+    ("region", "SS", "South Sudan"),
+    ("region", "ST", "São Tomé & Príncipe"),
+    ("region", "SV", "El Salvador"),
+    ("region", "SX", "Sint Maarten"),
+    ("region", "SY", "Syria"),
+    ("region", "SZ", "Eswatini"),
+    ("region", "TA", "Tristan da Cunha"),
+    ("region", "TC", "Turks & Caicos Islands"),
+    ("region", "TD", "Chad"),
+    ("region", "TF", "French Southern Territories"),
+    ("region", "TG", "Togo"),
+    ("region", "TH", "Thailand"),
+    ("region", "TJ", "Tajikistan"),
+    ("region", "TK", "Tokelau"),
+    ("region", "TL", "Timor-Leste"),
+    ("region", "TM", "Turkmenistan"),
+    ("region", "TN", "Tunisia"),
+    ("region", "TO", "Tonga"),
+    ("region", "TR", "Türkiye"),
+    ("region", "TT", "Trinidad & Tobago"),
+    ("region", "TV", "Tuvalu"),
+    ("region", "TW", "Taiwan"),
+    ("region", "TZ", "Tanzania"),
+    ("region", "UA", "Ukraine"),
+    ("region", "UG", "Uganda"),
+    ("region", "UM", "U.S. Outlying Islands"),
+    ("region", "UN", "United Nations"),
+    ("region", "US", "United States"),
+    ("region", "UY", "Uruguay"),
+    ("region", "UZ", "Uzbekistan"),
+    ("region", "VA", "Vatican City"),
+    ("region", "VC", "St. Vincent & Grenadines"),
+    ("region", "VE", "Venezuela"),
+    ("region", "VG", "British Virgin Islands"),
+    ("region", "VI", "U.S. Virgin Islands"),
+    ("region", "VN", "Vietnam"),
+    ("region", "VU", "Vanuatu"),
+    ("region", "WF", "Wallis & Futuna"),
+    ("region", "WS", "Samoa"),
+    ("region", "YE", "Yemen"),
+    ("region", "YT", "Mayotte"),
+    ("region", "ZA", "South Africa"),
+    ("region", "ZM", "Zambia"),
+    ("region", "ZW", "Zimbabwe"),
+    ("region", "ZZ", "Unknown Region"),
+    ("script", "Adlm", "Adlam"),
+    ("script", "Afak", "Afaka"),
+    ("script", "Aghb", "Caucasian Albanian"),
+    ("script", "Arab", "Arabic"),
+    ("script", "Aran", "Nastaliq"),
+    ("script", "Armi", "Imperial Aramaic"),
+    ("script", "Armn", "Armenian"),
+    ("script", "Avst", "Avestan"),
+    ("script", "Bali", "Balinese"),
+    ("script", "Bamu", "Bamum"),
+    ("script", "Bass", "Bassa Vah"),
+    ("script", "Batk", "Batak"),
+    ("script", "Beng", "Bangla"),
+    ("script", "Bhks", "Bhaiksuki"),
+    ("script", "Blis", "Blissymbols"),
+    ("script", "Bopo", "Bopomofo"),
+    ("script", "Brah", "Brahmi"),
+    ("script", "Brai", "Braille"),
+    ("script", "Bugi", "Buginese"),
+    ("script", "Buhd", "Buhid"),
+    ("script", "Cakm", "Chakma"),
+    ("script", "Cans", "Unified Canadian Aboriginal Syllabics"),
+    ("script", "Cari", "Carian"),
+    ("script", "Cher", "Cherokee"),
+    ("script", "Chrs", "Chorasmian"),
+    ("script", "Cirt", "Cirth"),
+    ("script", "Copt", "Coptic"),
+    ("script", "Cpmn", "Cypro-Minoan"),
+    ("script", "Cprt", "Cypriot"),
+    ("script", "Cyrl", "Cyrillic"),
+    ("script", "Cyrs", "Old Church Slavonic Cyrillic"),
+    ("script", "Deva", "Devanagari"),
+    ("script", "Diak", "Dives Akuru"),
+    ("script", "Dogr", "Dogra"),
+    ("script", "Dsrt", "Deseret"),
+    ("script", "Dupl", "Duployan shorthand"),
+    ("script", "Egyd", "Egyptian demotic"),
+    ("script", "Egyh", "Egyptian hieratic"),
+    ("script", "Egyp", "Egyptian hieroglyphs"),
+    ("script", "Elba", "Elbasan"),
+    ("script", "Elym", "Elymaic"),
+    ("script", "Ethi", "Ethiopic"),
+    ("script", "Gara", "Garay"),
+    ("script", "Geok", "Georgian Khutsuri"),
+    ("script", "Geor", "Georgian"),
+    ("script", "Glag", "Glagolitic"),
+    ("script", "Gong", "Gunjala Gondi"),
+    ("script", "Gonm", "Masaram Gondi"),
+    ("script", "Goth", "Gothic"),
+    ("script", "Gran", "Grantha"),
+    ("script", "Grek", "Greek"),
+    ("script", "Gujr", "Gujarati"),
+    ("script", "Gukh", "Gurung Khema"),
+    ("script", "Guru", "Gurmukhi"),
+    ("script", "Hanb", "Han with Bopomofo"),
+    ("script", "Hang", "Hangul"),
+    ("script", "Hani", "Han"),
+    ("script", "Hano", "Hanunoo"),
+    ("script", "Hans", "Simplified"),
+    ("script", "Hant", "Traditional"),
+    ("script", "Hatr", "Hatran"),
+    ("script", "Hebr", "Hebrew"),
+    ("script", "Hira", "Hiragana"),
+    ("script", "Hluw", "Anatolian Hieroglyphs"),
+    ("script", "Hmng", "Pahawh Hmong"),
+    ("script", "Hmnp", "Nyiakeng Puachue Hmong"),
+    ("script", "Hrkt", "Japanese syllabaries"),
+    ("script", "Hung", "Old Hungarian"),
+    ("script", "Inds", "Indus"),
+    ("script", "Ital", "Old Italic"),
+    ("script", "Java", "Javanese"),
+    ("script", "Jpan", "Japanese"),
+    ("script", "Jurc", "Jurchen"),
+    ("script", "Kali", "Kayah Li"),
+    ("script", "Kana", "Katakana"),
+    ("script", "Khar", "Kharoshthi"),
+    ("script", "Khmr", "Khmer"),
+    ("script", "Khoj", "Khojki"),
+    ("script", "Kits", "Khitan small script"),
+    ("script", "Knda", "Kannada"),
+    ("script", "Kore", "Korean"),
+    ("script", "Kpel", "Kpelle"),
+    ("script", "Krai", "Kirat Rai"),
+    ("script", "Kthi", "Kaithi"),
+    ("script", "Lana", "Lanna"),
+    ("script", "Laoo", "Lao"),
+    ("script", "Latf", "Fraktur Latin"),
+    ("script", "Latg", "Gaelic Latin"),
+    ("script", "Latn", "Latin"),
+    ("script", "Lepc", "Lepcha"),
+    ("script", "Limb", "Limbu"),
+    ("script", "Lina", "Linear A"),
+    ("script", "Linb", "Linear B"),
+    ("script", "Lisu", "Fraser"),
+    ("script", "Lyci", "Lycian"),
+    ("script", "Lydi", "Lydian"),
+    ("script", "Mahj", "Mahajani"),
+    ("script", "Maka", "Makasar"),
+    ("script", "Mand", "Mandaean"),
+    ("script", "Mani", "Manichaean"),
+    ("script", "Marc", "Marchen"),
+    ("script", "Maya", "Mayan hieroglyphs"),
+    ("script", "Medf", "Medefaidrin"),
+    ("script", "Mend", "Mende"),
+    ("script", "Merc", "Meroitic Cursive"),
+    ("script", "Mero", "Meroitic"),
+    ("script", "Mlym", "Malayalam"),
+    ("script", "Mong", "Mongolian"),
+    ("script", "Mroo", "Mro"),
+    ("script", "Mtei", "Meitei Mayek"),
+    ("script", "Mult", "Multani"),
+    ("script", "Mymr", "Myanmar"),
+    ("script", "Nagm", "Nag Mundari"),
+    ("script", "Nand", "Nandinagari"),
+    ("script", "Narb", "Old North Arabian"),
+    ("script", "Nbat", "Nabataean"),
+    ("script", "Nkgb", "Naxi Geba"),
+    ("script", "Nkoo", "N’Ko"),
+    ("script", "Nshu", "Nüshu"),
+    ("script", "Ogam", "Ogham"),
+    ("script", "Olck", "Ol Chiki"),
+    ("script", "Onao", "Ol Onal"),
+    ("script", "Orkh", "Orkhon"),
+    ("script", "Orya", "Odia"),
+    ("script", "Osge", "Osage"),
+    ("script", "Osma", "Osmanya"),
+    ("script", "Ougr", "Old Uyghur"),
+    ("script", "Palm", "Palmyrene"),
+    ("script", "Pauc", "Pau Cin Hau"),
+    ("script", "Perm", "Old Permic"),
+    ("script", "Phag", "Phags-pa"),
+    ("script", "Phli", "Inscriptional Pahlavi"),
+    ("script", "Phlp", "Psalter Pahlavi"),
+    ("script", "Phlv", "Book Pahlavi"),
+    ("script", "Phnx", "Phoenician"),
+    ("script", "Plrd", "Pollard Phonetic"),
+    ("script", "Prti", "Inscriptional Parthian"),
+    ("script", "Rjng", "Rejang"),
+    ("script", "Rohg", "Hanifi"),
+    ("script", "Roro", "Rongorongo"),
+    ("script", "Runr", "Runic"),
+    ("script", "Samr", "Samaritan"),
+    ("script", "Sara", "Sarati"),
+    ("script", "Sarb", "Old South Arabian"),
+    ("script", "Saur", "Saurashtra"),
+    ("script", "Sgnw", "SignWriting"),
+    ("script", "Shaw", "Shavian"),
+    ("script", "Shrd", "Sharada"),
+    ("script", "Sidd", "Siddham"),
+    ("script", "Sidt", "Sidetic"),
+    ("script", "Sind", "Khudawadi"),
+    ("script", "Sinh", "Sinhala"),
+    ("script", "Sogd", "Sogdian"),
+    ("script", "Sogo", "Old Sogdian"),
+    ("script", "Sora", "Sora Sompeng"),
+    ("script", "Soyo", "Soyombo"),
+    ("script", "Sund", "Sundanese"),
+    ("script", "Sunu", "Sunuwar"),
+    ("script", "Sylo", "Syloti Nagri"),
+    ("script", "Syrc", "Syriac"),
+    ("script", "Syre", "Estrangelo Syriac"),
+    ("script", "Syrj", "Western Syriac"),
+    ("script", "Syrn", "Eastern Syriac"),
+    ("script", "Tagb", "Tagbanwa"),
+    ("script", "Takr", "Takri"),
+    ("script", "Tale", "Tai Le"),
+    ("script", "Talu", "New Tai Lue"),
+    ("script", "Taml", "Tamil"),
+    ("script", "Tang", "Tangut"),
+    ("script", "Tavt", "Tai Viet"),
+    ("script", "Tayo", "Tai Yo"),
+    ("script", "Telu", "Telugu"),
+    ("script", "Teng", "Tengwar"),
+    ("script", "Tfng", "Tifinagh"),
+    ("script", "Tglg", "Tagalog"),
+    ("script", "Thaa", "Thaana"),
+    ("script", "Tibt", "Tibetan"),
+    ("script", "Tirh", "Tirhuta"),
+    ("script", "Tnsa", "Tangsa"),
+    ("script", "Todr", "Todhri"),
+    ("script", "Tols", "Tolong Siki"),
+    ("script", "Tutg", "Tulu-Tigalari"),
+    ("script", "Ugar", "Ugaritic"),
+    ("script", "Vaii", "Vai"),
+    ("script", "Visp", "Visible Speech"),
+    ("script", "Vith", "Vithkuqi"),
+    ("script", "Wara", "Varang Kshiti"),
+    ("script", "Wcho", "Wancho"),
+    ("script", "Wole", "Woleai"),
+    ("script", "Xpeo", "Old Persian"),
+    ("script", "Xsux", "Sumero-Akkadian Cuneiform"),
+    ("script", "Yezi", "Yezidi"),
+    ("script", "Yiii", "Yi"),
+    ("script", "Zanb", "Zanabazar Square"),
+    ("script", "Zinh", "Inherited"),
+    ("script", "Zmth", "Mathematical Notation"),
+    ("script", "Zsye", "Emoji"),
+    ("script", "Zsym", "Symbols"),
+    ("script", "Zxxx", "Unwritten"),
+    ("script", "Zyyy", "Common"),
+    ("script", "Zzzz", "Unknown Script"),
+];
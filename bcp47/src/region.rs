@@ -0,0 +1,181 @@
+// Region containment, UN M.49 as grouped by CLDR
+// `common/supplemental/supplementalData.xml` `<territoryContainment>`.
+
+/// Each macroregion with the regions it directly contains. A region can be
+/// in more than one group: Central America (013) is both in the Americas
+/// (019) and in Latin America (419). The world (001) and the continents come
+/// first, then the subregions; `ancestors` walks the table one level at a
+/// time, so the order only settles ties between groups at the same level.
+static CONTAINMENT: &[(&str, &[&str])] = &[
+    ("001", &["002", "009", "019", "142", "150"]),
+    ("002", &["011", "014", "015", "017", "018", "202"]),
+    ("202", &["011", "014", "017", "018"]),
+    ("003", &["013", "021", "029"]),
+    ("419", &["005", "013", "029"]),
+    ("019", &["003", "419", "005", "013", "021", "029"]),
+    ("142", &["030", "034", "035", "143", "145"]),
+    ("150", &["039", "151", "154", "155"]),
+    ("009", &["053", "054", "057", "061", "QO"]),
+    ("QO", &["AC", "AQ", "CP", "DG", "TA"]),
+    (
+        "011",
+        &[
+            "BF", "BJ", "CI", "CV", "GH", "GM", "GN", "GW", "LR", "ML", "MR", "NE", "NG", "SH",
+            "SL", "SN", "TG",
+        ],
+    ),
+    (
+        "014",
+        &[
+            "BI", "DJ", "ER", "ET", "IO", "KE", "KM", "MG", "MU", "MW", "MZ", "RE", "RW", "SC",
+            "SO", "SS", "TF", "TZ", "UG", "YT", "ZM", "ZW",
+        ],
+    ),
+    (
+        "015",
+        &["DZ", "EA", "EG", "EH", "IC", "LY", "MA", "SD", "TN"],
+    ),
+    (
+        "017",
+        &["AO", "CD", "CF", "CG", "CM", "GA", "GQ", "ST", "TD"],
+    ),
+    ("018", &["BW", "LS", "NA", "SZ", "ZA"]),
+    (
+        "005",
+        &[
+            "AR", "BO", "BR", "BV", "CL", "CO", "EC", "FK", "GF", "GS", "GY", "PE", "PY", "SR",
+            "UY", "VE",
+        ],
+    ),
+    ("013", &["BZ", "CR", "GT", "HN", "MX", "NI", "PA", "SV"]),
+    ("021", &["BM", "CA", "GL", "PM", "US"]),
+    (
+        "029",
+        &[
+            "AG", "AI", "AW", "BB", "BL", "BQ", "BS", "CU", "CW", "DM", "DO", "GD", "GP", "HT",
+            "JM", "KN", "KY", "LC", "MF", "MQ", "MS", "PR", "SX", "TC", "TT", "VC", "VG", "VI",
+        ],
+    ),
+    ("030", &["CN", "HK", "JP", "KP", "KR", "MN", "MO", "TW"]),
+    (
+        "034",
+        &["AF", "BD", "BT", "IN", "IR", "LK", "MV", "NP", "PK"],
+    ),
+    (
+        "035",
+        &[
+            "BN", "ID", "KH", "LA", "MM", "MY", "PH", "SG", "TH", "TL", "VN",
+        ],
+    ),
+    ("143", &["KG", "KZ", "TJ", "TM", "UZ"]),
+    (
+        "145",
+        &[
+            "AE", "AM", "AZ", "BH", "CY", "GE", "IL", "IQ", "JO", "KW", "LB", "OM", "PS", "QA",
+            "SA", "SY", "TR", "YE",
+        ],
+    ),
+    (
+        "039",
+        &[
+            "AD", "AL", "BA", "ES", "GI", "GR", "HR", "IT", "ME", "MK", "MT", "PT", "RS", "SI",
+            "SM", "VA", "XK",
+        ],
+    ),
+    (
+        "151",
+        &["BG", "BY", "CZ", "HU", "MD", "PL", "RO", "RU", "SK", "UA"],
+    ),
+    (
+        "154",
+        &[
+            "AX", "DK", "EE", "FI", "FO", "GB", "GG", "IE", "IM", "IS", "JE", "LT", "LV", "NO",
+            "SE", "SJ",
+        ],
+    ),
+    (
+        "155",
+        &["AT", "BE", "CH", "DE", "FR", "LI", "LU", "MC", "NL"],
+    ),
+    ("053", &["AU", "CC", "CX", "HM", "NF", "NZ"]),
+    ("054", &["FJ", "NC", "PG", "SB", "VU"]),
+    ("057", &["FM", "GU", "KI", "MH", "MP", "NR", "PW", "UM"]),
+    (
+        "061",
+        &["AS", "CK", "NU", "PF", "PN", "TK", "TO", "TV", "WF", "WS"],
+    ),
+];
+
+/// The regions that contain a region, nearest first, so `AR` gives `005`,
+/// `419`, `019` and `001`. Regions outside the table have none.
+pub fn ancestors(region: &str) -> Vec<&'static str> {
+    let mut ancestors: Vec<&'static str> = Vec::new();
+    let mut queue = vec![region];
+    while !queue.is_empty() {
+        let mut next = Vec::new();
+        for child in queue {
+            for (parent, _) in CONTAINMENT
+                .iter()
+                .filter(|(_, children)| children.contains(&child))
+            {
+                if !ancestors.contains(parent) {
+                    ancestors.push(parent);
+                    next.push(*parent);
+                }
+            }
+        }
+        queue = next;
+    }
+    ancestors
+}
+
+/// Whether `ancestor` contains `region`, directly or through other groups.
+pub fn contains(ancestor: &str, region: &str) -> bool {
+    ancestors(region).contains(&ancestor)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ancestors() {
+        assert_eq!(ancestors("AR"), vec!["005", "419", "019", "001"]);
+        assert_eq!(ancestors("MX"), vec!["013", "003", "419", "019", "001"]);
+        assert_eq!(ancestors("DE"), vec!["155", "150", "001"]);
+        assert_eq!(ancestors("150"), vec!["001"]);
+        assert!(ancestors("001").is_empty());
+        assert_eq!(ancestors("AQ"), vec!["QO", "009", "001"]);
+        assert_eq!(ancestors("IC"), vec!["015", "002", "001"]);
+        assert!(ancestors("QM").is_empty());
+    }
+
+    #[test]
+    fn test_contains() {
+        assert!(contains("419", "005"));
+        assert!(contains("419", "AR"));
+        assert!(contains("419", "MX"));
+        assert!(contains("150", "FR"));
+        assert!(contains("001", "JP"));
+        assert!(!contains("419", "US"));
+        assert!(!contains("FR", "FR"));
+    }
+
+    #[test]
+    fn test_regions_have_one_parent_group() {
+        // Every country is in exactly one M.49 subregion.
+        for (parent, children) in CONTAINMENT {
+            for child in children
+                .iter()
+                .filter(|c| c.chars().all(char::is_alphabetic))
+            {
+                let parents: Vec<&str> = CONTAINMENT
+                    .iter()
+                    .filter(|(_, c)| c.contains(child))
+                    .map(|(p, _)| *p)
+                    .collect();
+                assert_eq!(parents, vec![*parent], "{}", child);
+            }
+        }
+    }
+}
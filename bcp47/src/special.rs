@@ -0,0 +1,165 @@
+// Private use ranges and special-purpose subtags, RFC 5646 §2.2.1-§2.2.4
+// and §4.1.
+use crate::extism::*;
+
+/// The private use ranges of the registry, by subtag kind, as the registry
+/// records them (`language-qaa..qtz`). A range of one is a single subtag.
+static PRIVATE_USE: &[(&str, &str, &str)] = &[
+    ("language", "qaa", "qtz"),
+    ("script", "Qaaa", "Qabx"),
+    ("region", "AA", "AA"),
+    ("region", "QM", "QZ"),
+    ("region", "XA", "XZ"),
+    ("region", "ZZ", "ZZ"),
+];
+
+/// Subtags with a special meaning, with their registry description and
+/// what they tell about the content.
+static SPECIAL: &[(&str, &str, &str, &str)] = &[
+    (
+        "language",
+        "und",
+        "Undetermined",
+        "the language of the content is not known or not identified",
+    ),
+    (
+        "language",
+        "mul",
+        "Multiple languages",
+        "the content is in several languages",
+    ),
+    (
+        "language",
+        "zxx",
+        "No linguistic content",
+        "the content has no language, such as images or data",
+    ),
+    (
+        "language",
+        "mis",
+        "Uncoded languages",
+        "the language is known but has no code of its own",
+    ),
+    (
+        "script",
+        "Zyyy",
+        "Code for undetermined script",
+        "the content uses characters common to several scripts",
+    ),
+    (
+        "script",
+        "Zzzz",
+        "Code for uncoded script",
+        "the script is not known or has no code of its own",
+    ),
+    (
+        "region",
+        "QO",
+        "Outlying Oceania",
+        "the remote islands of Oceania, by the CLDR convention for this private use code",
+    ),
+    (
+        "region",
+        "XK",
+        "Kosovo",
+        "Kosovo, by the CLDR convention for this private use code",
+    ),
+    (
+        "region",
+        "ZZ",
+        "Private use",
+        "the region is not known, by the CLDR convention for this private use code",
+    ),
+];
+
+/// The registry range code (`qaa..qtz`, or `AA` for a single subtag) of the
+/// private use range a subtag falls in.
+pub fn private_use_range(kind: &str, subtag: &str) -> Option<String> {
+    let subtag = subtag.to_ascii_lowercase();
+    PRIVATE_USE
+        .iter()
+        .find(|(k, start, end)| {
+            *k == kind
+                && start.len() == subtag.len()
+                && start.to_ascii_lowercase() <= subtag
+                && subtag <= end.to_ascii_lowercase()
+        })
+        .map(|(_, start, end)| match start == end {
+            true => start.to_string(),
+            false => format!("{}..{}", start, end),
+        })
+}
+
+/// Whether CLDR gives a subtag a meaning of its own, even inside a private
+/// use range (`XK` for Kosovo).
+pub fn is_special(kind: &str, subtag: &str) -> bool {
+    SPECIAL
+        .iter()
+        .any(|(k, s, _, _)| *k == kind && s.eq_ignore_ascii_case(subtag))
+}
+
+/// A stand-in for the registry record of a private use range or special
+/// subtag, for hosts that don't carry it.
+pub fn record(kind: &str, subtag: &str) -> Option<Concept> {
+    let (code, display) = match SPECIAL
+        .iter()
+        .find(|(k, s, _, _)| *k == kind && s.eq_ignore_ascii_case(subtag))
+    {
+        Some((_, subtag, description, _)) => (subtag.to_string(), *description),
+        None => (private_use_range(kind, subtag)?, "Private use"),
+    };
+    Some(Concept {
+        code: format!("{}-{}", kind, code),
+        display: Some(display.to_string()),
+        ..Concept::default()
+    })
+}
+
+/// What a private use or special subtag means, for an informational detail.
+pub fn meaning(kind: &str, subtag: &str) -> Option<String> {
+    if let Some((_, _, description, meaning)) = SPECIAL
+        .iter()
+        .find(|(k, s, _, _)| *k == kind && s.eq_ignore_ascii_case(subtag))
+    {
+        return Some(format!("{} ({}): {}", subtag, description, meaning));
+    }
+    let range = private_use_range(kind, subtag)?;
+    Some(format!(
+        "{} is a private use {} subtag (range {}): its meaning is set by private agreement",
+        subtag, kind, range
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_private_use_range() {
+        assert_eq!(private_use_range("language", "qab").unwrap(), "qaa..qtz");
+        assert_eq!(private_use_range("language", "qtz").unwrap(), "qaa..qtz");
+        assert_eq!(private_use_range("language", "qua"), None);
+        assert_eq!(private_use_range("language", "qa"), None);
+        assert_eq!(private_use_range("script", "Qaab").unwrap(), "Qaaa..Qabx");
+        assert_eq!(private_use_range("script", "Qaby"), None);
+        assert_eq!(private_use_range("region", "AA").unwrap(), "AA");
+        assert_eq!(private_use_range("region", "QN").unwrap(), "QM..QZ");
+        assert_eq!(private_use_range("region", "XK").unwrap(), "XA..XZ");
+        assert_eq!(private_use_range("region", "QL"), None);
+        assert_eq!(private_use_range("region", "001"), None);
+    }
+
+    #[test]
+    fn test_record() {
+        assert_eq!(record("language", "qab").unwrap().code, "language-qaa..qtz");
+        assert_eq!(
+            record("script", "Zyyy").unwrap().display.unwrap(),
+            "Code for undetermined script"
+        );
+        assert_eq!(record("region", "ZZ").unwrap().code, "region-ZZ");
+        assert_eq!(record("region", "XK").unwrap().display.unwrap(), "Kosovo");
+        assert!(is_special("region", "XK"));
+        assert!(!is_special("region", "XL"));
+        assert!(record("language", "en").is_none());
+    }
+}
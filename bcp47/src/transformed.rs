@@ -0,0 +1,284 @@
+// Transformed content extension (`-t-`), RFC 6497 and UTS #35 §3.7.
+use crate::extism::*;
+use crate::grammar::parse_langtag;
+use crate::{lookup_record, registry_coding, LanguageTag};
+
+/// A `-t-` extension: the tag of the content's source, if given, followed by
+/// `tkey-tvalue` fields describing the transformation.
+#[derive(Debug, PartialEq, Clone)]
+pub struct TransformedExtension {
+    pub source: Option<LanguageTag>,
+    pub fields: Vec<Field>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Field {
+    pub key: String,
+    pub values: Vec<String>,
+}
+
+/// A field key from the CLDR transform definitions (`common/bcp47/transform*.xml`).
+pub(crate) struct FieldDefinition {
+    pub key: &'static str,
+    /// Property code the field is exposed as.
+    pub name: &'static str,
+    pub description: &'static str,
+}
+
+pub(crate) static FIELDS: &[FieldDefinition] = &[
+    FieldDefinition {
+        key: "m0",
+        name: "transformMechanism",
+        description: "Transform Mechanism",
+    },
+    FieldDefinition {
+        key: "s0",
+        name: "transformSource",
+        description: "Transform Source",
+    },
+    FieldDefinition {
+        key: "d0",
+        name: "transformDestination",
+        description: "Transform Destination",
+    },
+    FieldDefinition {
+        key: "i0",
+        name: "inputMethod",
+        description: "Input Method Engine",
+    },
+    FieldDefinition {
+        key: "k0",
+        name: "keyboard",
+        description: "Keyboard",
+    },
+    FieldDefinition {
+        key: "t0",
+        name: "machineTranslation",
+        description: "Machine Translation",
+    },
+    FieldDefinition {
+        key: "h0",
+        name: "hybridLocale",
+        description: "Hybrid Locale",
+    },
+    FieldDefinition {
+        key: "x0",
+        name: "transformPrivateUse",
+        description: "Private Use",
+    },
+];
+
+pub(crate) fn field_definition(key: &str) -> Option<&'static FieldDefinition> {
+    FIELDS.iter().find(|f| f.key == key)
+}
+
+/// `tkey = alpha digit`
+fn is_field_key(part: &str) -> bool {
+    let bytes = part.as_bytes();
+    bytes.len() == 2 && bytes[0].is_ascii_alphabetic() && bytes[1].is_ascii_digit()
+}
+
+impl Field {
+    pub fn value(&self) -> String {
+        self.values.join("-")
+    }
+}
+
+impl TransformedExtension {
+    /// Splits the subtags following `t` into the source tag and fields. The
+    /// subtags before the first field key are parsed as a language tag of
+    /// their own; each field value subtag must be 3-8 alphanumerics.
+    pub fn parse(parts: &[String]) -> Result<Self, ParseDetail> {
+        let source_len = parts
+            .iter()
+            .position(|p| is_field_key(p))
+            .unwrap_or(parts.len());
+        let source = match source_len {
+            0 => None,
+            _ => {
+                let code = parts[..source_len].join("-");
+                let tag = parse_langtag(&code).map_err(|detail| ParseDetail {
+                    key: "transformedSource".to_string(),
+                    severity: Severity::Error,
+                    value: ValueX::ValueString(format!(
+                        "Invalid source tag {} in transformed extension: {}",
+                        code,
+                        detail.value.as_str().unwrap_or_default()
+                    )),
+                    location: Location {
+                        start: None,
+                        end: None,
+                        ..detail.location
+                    },
+                })?;
+                Some(tag.canonical_case())
+            }
+        };
+
+        let no_value = |field: &Field, index: usize| ParseDetail {
+            key: "extension".to_string(),
+            severity: Severity::Error,
+            value: ValueX::ValueString(format!(
+                "Transformed extension field {} has no value",
+                field.key
+            )),
+            location: Location::subtag(index),
+        };
+        let mut fields: Vec<Field> = Vec::new();
+        for (i, part) in parts.iter().enumerate().skip(source_len) {
+            if is_field_key(part) {
+                if let Some(field) = fields.last().filter(|f| f.values.is_empty()) {
+                    return Err(no_value(field, i - 1));
+                }
+                fields.push(Field {
+                    key: part.clone(),
+                    values: Vec::new(),
+                });
+            } else if part.len() < 3 {
+                return Err(ParseDetail {
+                    key: "extension".to_string(),
+                    severity: Severity::Error,
+                    value: ValueX::ValueString(format!(
+                        "Invalid transformed extension subtag {}: expected a field key or a 3-8 character value",
+                        part
+                    )),
+                    location: Location::subtag(i),
+                });
+            } else if let Some(field) = fields.last_mut() {
+                field.values.push(part.clone());
+            }
+        }
+        if let Some(field) = fields.last().filter(|f| f.values.is_empty()) {
+            return Err(no_value(field, parts.len() - 1));
+        }
+        Ok(TransformedExtension { source, fields })
+    }
+
+    /// The source tag and its registered subtags (`transformedSource`,
+    /// `transformedSourceLanguage`, ...), then one property per field named
+    /// after its key. Source subtags missing from the registry and unknown
+    /// or repeated field keys are reported, located by subtag index within
+    /// the extension. A source subtag is only looked up when `lookup` accepts
+    /// its property code.
+    pub fn properties(
+        &self,
+        db: &dyn TerminologyDb,
+        lookup: &dyn Fn(&str) -> bool,
+    ) -> (Vec<Property>, Vec<ParseDetail>) {
+        let mut properties = Vec::new();
+        let mut details = Vec::new();
+        let mut index = 0;
+
+        if let Some(source) = &self.source {
+            properties.push(Property {
+                code: "transformedSource".to_string(),
+                value: ValueX::ValueString(source.to_string()),
+            });
+            index = source.to_string().split('-').count();
+            for ((subtag, kind, severity), subtag_index) in
+                source.properties().into_iter().zip(source.subtag_indices())
+            {
+                let code = format!(
+                    "transformedSource{}{}",
+                    kind[..1].to_uppercase(),
+                    &kind[1..]
+                );
+                let Some(severity) = severity.filter(|_| lookup(&code)) else {
+                    continue;
+                };
+                // Only the record's code and display are used.
+                match lookup_record(db, kind, &subtag, &[], false) {
+                    Some(record) => properties.push(Property {
+                        code,
+                        value: registry_coding(record.code, record.display),
+                    }),
+                    None => details.push(ParseDetail {
+                        key: "transformedSource".to_string(),
+                        severity,
+                        value: ValueX::ValueString(format!(
+                            "Invalid {} subtag in transformed source {}: {}",
+                            kind, source, subtag
+                        )),
+                        location: Location::subtag(subtag_index),
+                    }),
+                }
+            }
+        }
+
+        for (i, field) in self.fields.iter().enumerate() {
+            let key_index = index;
+            index += 1 + field.values.len();
+            let Some(definition) = field_definition(&field.key) else {
+                details.push(ParseDetail {
+                    key: "extension".to_string(),
+                    severity: Severity::Warning,
+                    value: ValueX::ValueString(format!(
+                        "Unknown transformed extension field: {}",
+                        field.key
+                    )),
+                    location: Location::subtag(key_index),
+                });
+                continue;
+            };
+            if self.fields[..i].iter().any(|f| f.key == field.key) {
+                details.push(ParseDetail {
+                    key: definition.name.to_string(),
+                    severity: Severity::Warning,
+                    value: ValueX::ValueString(format!(
+                        "{} field {} appears more than once; only the first is used",
+                        definition.description, field.key
+                    )),
+                    location: Location::subtag(key_index),
+                });
+                continue;
+            }
+            properties.push(Property {
+                code: definition.name.to_string(),
+                value: ValueX::ValueString(field.value()),
+            });
+        }
+        (properties, details)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parts(extension: &str) -> Vec<String> {
+        extension.split('-').map(str::to_string).collect()
+    }
+
+    #[test]
+    fn test_parse_source_and_fields() {
+        let transformed = TransformedExtension::parse(&parts("und-latn-m0-ungegn-2007")).unwrap();
+        assert_eq!(transformed.source.unwrap().to_string(), "und-Latn");
+        assert_eq!(
+            transformed.fields,
+            vec![Field {
+                key: "m0".to_string(),
+                values: vec!["ungegn".to_string(), "2007".to_string()],
+            }]
+        );
+
+        let transformed = TransformedExtension::parse(&parts("k0-windows-x0-mine")).unwrap();
+        assert_eq!(transformed.source, None);
+        let keys: Vec<_> = transformed.fields.iter().map(|f| f.key.as_str()).collect();
+        assert_eq!(keys, vec!["k0", "x0"]);
+    }
+
+    #[test]
+    fn test_parse_invalid_source() {
+        let detail = TransformedExtension::parse(&parts("en-us1234567-m0-ungegn")).unwrap_err();
+        assert_eq!(detail.severity, Severity::Error);
+        assert_eq!(detail.key, "transformedSource");
+    }
+
+    #[test]
+    fn test_parse_invalid_field() {
+        let detail = TransformedExtension::parse(&parts("ja-m0-i0-handwrit")).unwrap_err();
+        assert_eq!(detail.key, "extension");
+        let detail = TransformedExtension::parse(&parts("ja-m0-ab")).unwrap_err();
+        assert_eq!(detail.key, "extension");
+    }
+}
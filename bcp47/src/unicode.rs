@@ -0,0 +1,470 @@
+// Unicode locale extension (`-u-`), RFC 6067 and UTS #35 §3.6.
+use crate::extism::*;
+
+/// A `-u-` extension split into its attributes and `key-type` keywords.
+#[derive(Debug, PartialEq, Clone)]
+pub struct UnicodeExtension {
+    pub attributes: Vec<String>,
+    pub keywords: Vec<Keyword>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct Keyword {
+    pub key: String,
+    /// Subtags of the type; empty when the key has no type, which UTS #35
+    /// reads as `true`.
+    pub types: Vec<String>,
+}
+
+enum Types {
+    Listed(&'static [&'static str]),
+    Matching(fn(&str) -> bool),
+}
+
+/// A key from the CLDR BCP 47 key definitions (`common/bcp47/*.xml`).
+pub(crate) struct KeyDefinition {
+    pub key: &'static str,
+    /// Property code the keyword is exposed as.
+    pub name: &'static str,
+    pub description: &'static str,
+    types: Types,
+}
+
+impl KeyDefinition {
+    fn accepts(&self, value: &str) -> bool {
+        match self.types {
+            Types::Listed(types) => types.contains(&value),
+            Types::Matching(rule) => rule(value),
+        }
+    }
+}
+
+const BOOLEAN: Types = Types::Listed(&["true", "false"]);
+
+fn is_currency(value: &str) -> bool {
+    value.len() == 3 && value.chars().all(|c| c.is_ascii_alphabetic())
+}
+
+/// `unicode_subdivision_id`: a region followed by a 1-4 character suffix,
+/// e.g. `usca`, or `uszzzz` for the whole region.
+fn is_subdivision(value: &str) -> bool {
+    let region_len = match value.chars().next() {
+        Some(c) if c.is_ascii_digit() => 3,
+        _ => 2,
+    };
+    (region_len + 1..=region_len + 4).contains(&value.len())
+        && value.chars().all(|c| c.is_ascii_alphanumeric())
+        && value[..region_len].chars().all(|c| match region_len {
+            3 => c.is_ascii_digit(),
+            _ => c.is_ascii_alphabetic(),
+        })
+}
+
+fn is_time_zone(value: &str) -> bool {
+    (3..=8).contains(&value.len()) && value.chars().all(|c| c.is_ascii_alphanumeric())
+}
+
+fn is_script_list(value: &str) -> bool {
+    value
+        .split('-')
+        .all(|s| s.len() == 4 && s.chars().all(|c| c.is_ascii_alphabetic()))
+}
+
+fn is_reorder_list(value: &str) -> bool {
+    const GROUPS: &[&str] = &[
+        "space", "punct", "symbol", "currency", "digit", "others", "zzzz",
+    ];
+    value
+        .split('-')
+        .all(|s| GROUPS.contains(&s) || is_script_list(s))
+}
+
+pub(crate) static KEYS: &[KeyDefinition] = &[
+    KeyDefinition {
+        key: "ca",
+        name: "calendar",
+        description: "Calendar",
+        types: Types::Listed(&[
+            "buddhist",
+            "chinese",
+            "coptic",
+            "dangi",
+            "ethioaa",
+            "ethiopic",
+            "ethiopic-amete-alem",
+            "gregory",
+            "hebrew",
+            "indian",
+            "islamic",
+            "islamic-civil",
+            "islamic-rgsa",
+            "islamic-tbla",
+            "islamic-umalqura",
+            "iso8601",
+            "japanese",
+            "persian",
+            "roc",
+        ]),
+    },
+    KeyDefinition {
+        key: "co",
+        name: "collation",
+        description: "Sort Order",
+        types: Types::Listed(&[
+            "big5han", "compat", "dict", "direct", "ducet", "emoji", "eor", "gb2312", "phonebk",
+            "phonetic", "pinyin", "reformed", "search", "searchjl", "standard", "stroke", "trad",
+            "unihan", "zhuyin",
+        ]),
+    },
+    KeyDefinition {
+        key: "cu",
+        name: "currency",
+        description: "Currency",
+        types: Types::Matching(is_currency),
+    },
+    KeyDefinition {
+        key: "cf",
+        name: "currencyFormat",
+        description: "Currency Format",
+        types: Types::Listed(&["standard", "account"]),
+    },
+    KeyDefinition {
+        key: "dx",
+        name: "dictionaryBreakExclusion",
+        description: "Dictionary Break Script Exclusions",
+        types: Types::Matching(is_script_list),
+    },
+    KeyDefinition {
+        key: "em",
+        name: "emoji",
+        description: "Emoji Presentation Style",
+        types: Types::Listed(&["emoji", "text", "default"]),
+    },
+    KeyDefinition {
+        key: "fw",
+        name: "firstDayOfWeek",
+        description: "First Day of Week",
+        types: Types::Listed(&["sun", "mon", "tue", "wed", "thu", "fri", "sat"]),
+    },
+    KeyDefinition {
+        key: "hc",
+        name: "hourCycle",
+        description: "Hour Cycle",
+        types: Types::Listed(&["h11", "h12", "h23", "h24"]),
+    },
+    KeyDefinition {
+        key: "ka",
+        name: "collationAlternate",
+        description: "Collation Alternate Handling",
+        types: Types::Listed(&["noignore", "shifted"]),
+    },
+    KeyDefinition {
+        key: "kb",
+        name: "collationBackwards",
+        description: "Collation Backward Second Level",
+        types: BOOLEAN,
+    },
+    KeyDefinition {
+        key: "kc",
+        name: "collationCaseLevel",
+        description: "Collation Case Level",
+        types: BOOLEAN,
+    },
+    KeyDefinition {
+        key: "kf",
+        name: "collationCaseFirst",
+        description: "Collation Case First",
+        types: Types::Listed(&["upper", "lower", "false"]),
+    },
+    KeyDefinition {
+        key: "kk",
+        name: "collationNormalization",
+        description: "Collation Normalization",
+        types: BOOLEAN,
+    },
+    KeyDefinition {
+        key: "kn",
+        name: "collationNumeric",
+        description: "Numeric Sorting",
+        types: BOOLEAN,
+    },
+    KeyDefinition {
+        key: "kr",
+        name: "collationReorder",
+        description: "Collation Reorder Codes",
+        types: Types::Matching(is_reorder_list),
+    },
+    KeyDefinition {
+        key: "ks",
+        name: "collationStrength",
+        description: "Collation Strength",
+        types: Types::Listed(&["level1", "level2", "level3", "level4", "identic"]),
+    },
+    KeyDefinition {
+        key: "kv",
+        name: "collationMaxVariable",
+        description: "Collation Max Variable",
+        types: Types::Listed(&["space", "punct", "symbol", "currency"]),
+    },
+    KeyDefinition {
+        key: "lb",
+        name: "lineBreak",
+        description: "Line Break Style",
+        types: Types::Listed(&["strict", "normal", "loose"]),
+    },
+    KeyDefinition {
+        key: "lw",
+        name: "lineBreakWord",
+        description: "Line Break Word Handling",
+        types: Types::Listed(&["normal", "breakall", "keepall", "phrase"]),
+    },
+    KeyDefinition {
+        key: "ms",
+        name: "measurementSystem",
+        description: "Measurement System",
+        types: Types::Listed(&["metric", "ussystem", "uksystem"]),
+    },
+    KeyDefinition {
+        key: "mu",
+        name: "measurementUnit",
+        description: "Measurement Unit Override",
+        types: Types::Listed(&["celsius", "kelvin", "fahrenhe"]),
+    },
+    KeyDefinition {
+        key: "nu",
+        name: "numbering",
+        description: "Numbers",
+        types: Types::Listed(&[
+            "adlm", "ahom", "arab", "arabext", "armn", "armnlow", "bali", "beng", "bhks", "brah",
+            "cakm", "cham", "cyrl", "deva", "diak", "ethi", "finance", "fullwide", "geor", "gong",
+            "gonm", "grek", "greklow", "gujr", "guru", "hanidays", "hanidec", "hans", "hansfin",
+            "hant", "hantfin", "hebr", "hmng", "hmnp", "java", "jpan", "jpanfin", "jpanyear",
+            "kali", "kawi", "khmr", "knda", "lana", "lanatham", "laoo", "latn", "lepc", "limb",
+            "mathbold", "mathdbl", "mathmono", "mathsanb", "mathsans", "mlym", "modi", "mong",
+            "mroo", "mtei", "mymr", "mymrshan", "mymrtlng", "nagm", "native", "newa", "nkoo",
+            "olck", "orya", "osma", "rohg", "roman", "romanlow", "saur", "segment", "shrd", "sind",
+            "sinh", "sora", "sund", "takr", "talu", "taml", "tamldec", "tnsa", "telu", "thai",
+            "tibt", "tirh", "traditio", "vaii", "wara", "wcho",
+        ]),
+    },
+    KeyDefinition {
+        key: "rg",
+        name: "regionOverride",
+        description: "Region Override",
+        types: Types::Matching(is_subdivision),
+    },
+    KeyDefinition {
+        key: "sd",
+        name: "subdivision",
+        description: "Regional Subdivision",
+        types: Types::Matching(is_subdivision),
+    },
+    KeyDefinition {
+        key: "ss",
+        name: "sentenceBreakSuppression",
+        description: "Sentence Break Suppressions",
+        types: Types::Listed(&["none", "standard"]),
+    },
+    KeyDefinition {
+        key: "tz",
+        name: "timezone",
+        description: "Time Zone",
+        types: Types::Matching(is_time_zone),
+    },
+    KeyDefinition {
+        key: "va",
+        name: "localeVariant",
+        description: "Common Variant Type",
+        types: Types::Listed(&["posix"]),
+    },
+];
+
+pub(crate) fn key_definition(key: &str) -> Option<&'static KeyDefinition> {
+    KEYS.iter().find(|k| k.key == key)
+}
+
+impl Keyword {
+    /// The type as a single value, e.g. `islamic-civil`, with a missing
+    /// type read as `true`.
+    pub fn value(&self) -> String {
+        match self.types.len() {
+            0 => "true".to_string(),
+            _ => self.types.join("-"),
+        }
+    }
+}
+
+impl UnicodeExtension {
+    /// Splits the subtags following `u` into attributes and keywords. The
+    /// tag grammar has already checked that each subtag is 2-8 alphanumerics;
+    /// here two-character subtags start a keyword and longer ones are either
+    /// attributes (before the first key) or part of a type.
+    pub fn parse(parts: &[String]) -> Result<Self, ParseDetail> {
+        let mut attributes = Vec::new();
+        let mut keywords: Vec<Keyword> = Vec::new();
+        for (i, part) in parts.iter().enumerate() {
+            if part.len() == 2 {
+                if !part.as_bytes()[1].is_ascii_alphabetic() {
+                    return Err(ParseDetail {
+                        key: "extension".to_string(),
+                        severity: Severity::Error,
+                        value: ValueX::ValueString(format!(
+                            "Invalid Unicode extension key {}: expected an alphanumeric followed by a letter",
+                            part
+                        )),
+                        location: Location::subtag(i),
+                    });
+                }
+                keywords.push(Keyword {
+                    key: part.clone(),
+                    types: Vec::new(),
+                });
+            } else if let Some(keyword) = keywords.last_mut() {
+                keyword.types.push(part.clone());
+            } else {
+                attributes.push(part.clone());
+            }
+        }
+        Ok(UnicodeExtension {
+            attributes,
+            keywords,
+        })
+    }
+
+    /// One property per keyword, named after its key (`calendar`,
+    /// `collation`, ...), plus warnings for keys and types that aren't in
+    /// the BCP 47 key definitions. Details locate the subtag they concern
+    /// by its index among the extension's subtags.
+    pub fn properties(&self) -> (Vec<Property>, Vec<ParseDetail>) {
+        let mut properties: Vec<Property> = self
+            .attributes
+            .iter()
+            .map(|attribute| Property {
+                code: "unicodeAttribute".to_string(),
+                value: ValueX::ValueString(attribute.clone()),
+            })
+            .collect();
+        let mut details = Vec::new();
+        let mut index = self.attributes.len();
+
+        for (i, keyword) in self.keywords.iter().enumerate() {
+            let key_index = index;
+            index += 1 + keyword.types.len();
+            if self.keywords[..i].iter().any(|k| k.key == keyword.key) {
+                details.push(ParseDetail {
+                    key: "extension".to_string(),
+                    severity: Severity::Warning,
+                    value: ValueX::ValueString(format!(
+                        "Unicode extension key {} appears more than once; only the first is used",
+                        keyword.key
+                    )),
+                    location: Location::subtag(key_index),
+                });
+                continue;
+            }
+            let Some(definition) = key_definition(&keyword.key) else {
+                details.push(ParseDetail {
+                    key: "extension".to_string(),
+                    severity: Severity::Warning,
+                    value: ValueX::ValueString(format!(
+                        "Unknown Unicode extension key: {}",
+                        keyword.key
+                    )),
+                    location: Location::subtag(key_index),
+                });
+                continue;
+            };
+            let value = keyword.value();
+            if !definition.accepts(&value) {
+                details.push(ParseDetail {
+                    key: definition.name.to_string(),
+                    severity: Severity::Warning,
+                    value: ValueX::ValueString(format!(
+                        "Invalid {} type for Unicode extension key {}: {}",
+                        definition.description.to_lowercase(),
+                        keyword.key,
+                        value
+                    )),
+                    location: Location::subtag(key_index + keyword.types.len().min(1)),
+                });
+            }
+            properties.push(Property {
+                code: definition.name.to_string(),
+                value: ValueX::ValueString(value),
+            });
+        }
+        (properties, details)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parts(extension: &str) -> Vec<String> {
+        extension.split('-').map(str::to_string).collect()
+    }
+
+    fn string_properties(properties: &[Property]) -> Vec<(&str, &str)> {
+        properties
+            .iter()
+            .map(|p| (p.code.as_str(), p.value.as_str().unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_attributes_and_keywords() {
+        let unicode = UnicodeExtension::parse(&parts("attr-ca-islamic-civil-nu-arab-kn")).unwrap();
+        assert_eq!(unicode.attributes, vec!["attr"]);
+        let keywords: Vec<_> = unicode
+            .keywords
+            .iter()
+            .map(|k| (k.key.as_str(), k.value()))
+            .collect();
+        assert_eq!(
+            keywords,
+            vec![
+                ("ca", "islamic-civil".to_string()),
+                ("nu", "arab".to_string()),
+                ("kn", "true".to_string()),
+            ]
+        );
+
+        let (properties, details) = unicode.properties();
+        assert!(details.is_empty(), "{:?}", details);
+        assert_eq!(
+            string_properties(&properties),
+            vec![
+                ("unicodeAttribute", "attr"),
+                ("calendar", "islamic-civil"),
+                ("numbering", "arab"),
+                ("collationNumeric", "true"),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_validate_against_key_definitions() {
+        let unicode =
+            UnicodeExtension::parse(&parts("ca-gregory-hc-h13-zz-abc-rg-uszzzz-ca-roc")).unwrap();
+        let (properties, details) = unicode.properties();
+        assert_eq!(
+            string_properties(&properties),
+            vec![
+                ("calendar", "gregory"),
+                ("hourCycle", "h13"),
+                ("regionOverride", "uszzzz"),
+            ]
+        );
+        let keys: Vec<_> = details.iter().map(|d| d.key.as_str()).collect();
+        assert_eq!(keys, vec!["hourCycle", "extension", "extension"]);
+        assert!(details.iter().all(|d| d.severity == Severity::Warning));
+    }
+
+    #[test]
+    fn test_parse_invalid_key() {
+        let detail = UnicodeExtension::parse(&parts("a1-gregory")).unwrap_err();
+        assert_eq!(detail.severity, Severity::Error);
+        assert_eq!(detail.key, "extension");
+    }
+}
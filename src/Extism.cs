@@ -158,6 +158,19 @@ public class Concept
 
     [JsonPropertyName("properties")]
     public List<Property> Properties { get; set; } = new List<Property>();
+
+    [JsonPropertyName("designations")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public List<Designation>? Designations { get; set; }
+}
+
+public class Designation
+{
+    [JsonPropertyName("language")]
+    public string? Language { get; set; }
+
+    [JsonPropertyName("value")]
+    public string? Value { get; set; }
 }
 
 [JsonConverter(typeof(JsonStringEnumConverter))]
@@ -187,6 +200,15 @@ public class ParseDetail
 
     [JsonIgnore]
     public ValueX? Value { get; set; }
+
+    [JsonPropertyName("start")]
+    public int? Start { get; set; }
+
+    [JsonPropertyName("end")]
+    public int? End { get; set; }
+
+    [JsonPropertyName("subtagIndex")]
+    public int? SubtagIndex { get; set; }
 }
 
 public class ParseDetailConverter : JsonConverter<ParseDetail>
@@ -224,6 +246,15 @@ public class ParseDetailConverter : JsonConverter<ParseDetail>
                     case "valueDecimal":
                         parseDetail.Value = new ValueDecimal { Value = reader.GetString() ?? "" };
                         break;
+                    case "start":
+                        parseDetail.Start = reader.GetInt32();
+                        break;
+                    case "end":
+                        parseDetail.End = reader.GetInt32();
+                        break;
+                    case "subtagIndex":
+                        parseDetail.SubtagIndex = reader.GetInt32();
+                        break;
                 }
             }
         }
@@ -253,6 +284,13 @@ public class ParseDetailConverter : JsonConverter<ParseDetail>
                 break;
         }
 
+        if (value?.Start is int start)
+            writer.WriteNumber("start", start);
+        if (value?.End is int end)
+            writer.WriteNumber("end", end);
+        if (value?.SubtagIndex is int subtagIndex)
+            writer.WriteNumber("subtagIndex", subtagIndex);
+
         writer.WriteEndObject();
     }
 }
@@ -266,6 +304,18 @@ public class ParseResponse
     public Concept? Concept { get; set; }
 }
 
+public class ParseBatchRequest
+{
+    [JsonPropertyName("requests")]
+    public List<ParseRequest>? Requests { get; set; }
+}
+
+public class ParseBatchResponse
+{
+    [JsonPropertyName("responses")]
+    public List<ParseResponse>? Responses { get; set; }
+}
+
 // TerminologyDb.cs
 
 public class LookupRequest
@@ -275,6 +325,9 @@ public class LookupRequest
 
     [JsonPropertyName("properties")]
     public List<string>? Properties { get; set; }
+
+    [JsonPropertyName("designations")]
+    public bool Designations { get; set; }
 }
 
 public class LookupResponse
@@ -290,6 +343,23 @@ public class SubsumesRequest
 
     [JsonPropertyName("descendant")]
     public string? Descendant { get; set; }
+
+    [JsonPropertyName("modes")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public List<SubsumesMode>? Modes { get; set; }
+}
+
+[JsonConverter(typeof(SubsumesModeConverter))]
+public enum SubsumesMode
+{
+    Macrolanguage,
+    LikelySubtags,
+    Collection
+}
+
+public class SubsumesModeConverter : JsonStringEnumConverter<SubsumesMode>
+{
+    public SubsumesModeConverter() : base(JsonNamingPolicy.KebabCaseLower) { }
 }
 
 public class SubsumesResponse
@@ -307,4 +377,89 @@ public class ParseRequest
 
     [JsonPropertyName("properties")]
     public List<string>? Properties { get; set; }
+
+    [JsonPropertyName("conformance")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public Conformance? Conformance { get; set; }
+
+    [JsonPropertyName("displayLanguage")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? DisplayLanguage { get; set; }
+
+    [JsonPropertyName("displayStyle")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public DisplayStyle? DisplayStyle { get; set; }
+
+    [JsonPropertyName("asOf")]
+    [JsonIgnore(Condition = JsonIgnoreCondition.WhenWritingNull)]
+    public string? AsOf { get; set; }
+}
+
+[JsonConverter(typeof(DisplayStyleConverter))]
+public enum DisplayStyle
+{
+    Long,
+    Short,
+    Menu
+}
+
+public class DisplayStyleConverter : JsonStringEnumConverter<DisplayStyle>
+{
+    public DisplayStyleConverter() : base(JsonNamingPolicy.KebabCaseLower) { }
+}
+
+[JsonConverter(typeof(ConformanceConverter))]
+public enum Conformance
+{
+    WellFormed,
+    Valid,
+    Canonical
+}
+
+// The plugin expects "well-formed", "valid" and "canonical".
+public class ConformanceConverter : JsonStringEnumConverter<Conformance>
+{
+    public ConformanceConverter() : base(JsonNamingPolicy.KebabCaseLower) { }
+}
+
+public class FilterRequest
+{
+    [JsonPropertyName("ranges")]
+    public List<string>? Ranges { get; set; }
+
+    [JsonPropertyName("tags")]
+    public List<string>? Tags { get; set; }
+
+    [JsonPropertyName("extended")]
+    public bool Extended { get; set; }
+}
+
+public class FilterResponse
+{
+    [JsonPropertyName("tags")]
+    public List<string>? Tags { get; set; }
+
+    [JsonPropertyName("details")]
+    public List<ParseDetail>? Details { get; set; }
+}
+
+public class RangeLookupRequest
+{
+    [JsonPropertyName("ranges")]
+    public List<string>? Ranges { get; set; }
+
+    [JsonPropertyName("tags")]
+    public List<string>? Tags { get; set; }
+
+    [JsonPropertyName("default")]
+    public string? Default { get; set; }
+}
+
+public class RangeLookupResponse
+{
+    [JsonPropertyName("tag")]
+    public string? Tag { get; set; }
+
+    [JsonPropertyName("details")]
+    public List<ParseDetail>? Details { get; set; }
 }
\ No newline at end of file
@@ -80,7 +80,7 @@ public class SqliteManager
                         var key = plugin.ReadString(reqOffset);
                         Console.WriteLine($"Looking up key={key} on {canonicalUrl} {canonicalVersion}");
                         var reqObject = System.Text.Json.JsonSerializer.Deserialize<LookupRequest>(key);
-                        var concept = QueryConcept(reqObject!.Code!, canonicalUrl!, canonicalVersion);
+                        var concept = QueryConcept(reqObject!.Code!, canonicalUrl!, canonicalVersion, propertyCodes: reqObject.Properties, designations: reqObject.Designations);
                         Console.WriteLine($"Parsed key={System.Text.Json.JsonSerializer.Serialize(reqObject)}");
                             var resJson = System.Text.Json.JsonSerializer.Serialize(new LookupResponse { Concept = concept}  );
                             Console.WriteLine($"Returning {resJson}");
@@ -184,7 +184,7 @@ public class SqliteManager
         }
     }
 
-    public Concept? QueryConcept(string code, string canonicalUrl, string? canonicalVersion = null)
+    public Concept? QueryConcept(string code, string canonicalUrl, string? canonicalVersion = null, List<string>? propertyCodes = null, bool designations = false)
     {
         var db = GetDbByCanonicalSystem(canonicalUrl, canonicalVersion);
         if (db == null)
@@ -196,15 +196,25 @@ public class SqliteManager
         connection.Open();
         AttachDatabase(connection, db);
 
+        // Only the requested properties; the concept row is kept even when
+        // it has none of them.
+        var propertyParams = propertyCodes?.Select((_, i) => $"@property{i}").ToList();
+        var propertyFilter = propertyParams != null
+            ? $"AND p.property_code IN ({string.Join(", ", propertyParams.DefaultIfEmpty("NULL"))})"
+            : "";
         var query = @$"
         SELECT c.code, c.display, p.property_code, pt.type, p.target_value
         FROM {db.Name}.Concepts c
-        LEFT JOIN {db.Name}.ConceptProperty p ON c.id = p.concept_id
+        LEFT JOIN {db.Name}.ConceptProperty p ON c.id = p.concept_id {propertyFilter}
         LEFT JOIN {db.Name}.PropertyTypes pt ON pt.code = p.property_code
         WHERE c.code = @code";
 
         using var command = new SqliteCommand(query, connection);
         command.Parameters.AddWithValue("@code", code);
+        for (var i = 0; i < (propertyCodes?.Count ?? 0); i++)
+        {
+            command.Parameters.AddWithValue($"@property{i}", propertyCodes![i]);
+        }
 
         using var reader = command.ExecuteReader();
         if (!reader.HasRows)
@@ -235,6 +245,35 @@ public class SqliteManager
             .ToList();
 
         concept.Properties.AddRange(properties);
+
+        // Designations only when the caller reads them, e.g. for a display
+        // language.
+        if (!designations)
+        {
+            return concept;
+        }
+
+        var designationQuery = @$"
+        SELECT d.language, d.value
+        FROM {db.Name}.ConceptDesignations d
+        JOIN {db.Name}.Concepts c ON c.id = d.concept_id
+        WHERE c.code = @code";
+
+        using var designationCommand = new SqliteCommand(designationQuery, connection);
+        designationCommand.Parameters.AddWithValue("@code", code);
+
+        using var designationReader = designationCommand.ExecuteReader();
+        var conceptDesignations = designationReader.Cast<IDataRecord>()
+            .Select(record => new Designation
+            {
+                Language = record["language"] == DBNull.Value ? null : (string)record["language"],
+                Value = (string)record["value"]
+            })
+            .ToList();
+        if (conceptDesignations.Count > 0)
+        {
+            concept.Designations = conceptDesignations;
+        }
         return concept;
     }
 